@@ -0,0 +1,75 @@
+use std::{
+    fs::{self, File},
+    io::{self, BufRead, BufReader, Read},
+    path::PathBuf,
+};
+
+/// Where the puzzle input of a day is read from.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Source {
+    /// The example from the puzzle text, bundled with the day.
+    Example,
+    /// The puzzle input piped into the runner.
+    Stdin,
+    /// A puzzle input file.
+    File(PathBuf),
+    /// The puzzle input at `dayN/src/input.txt`.
+    Default,
+}
+
+impl Source {
+    pub fn new(example: bool, path: Option<PathBuf>) -> Self {
+        match (example, path) {
+            (true, _) => Source::Example,
+            (false, Some(path)) if path.as_os_str() == "-" => Source::Stdin,
+            (false, Some(path)) => Source::File(path),
+            (false, None) => Source::Default,
+        }
+    }
+
+    /// Reads the whole input, with `\r\n` line endings turned into `\n` so the days only
+    /// have to deal with one kind.
+    pub fn read(&self, day: u8, example: &str) -> Result<String, String> {
+        let input = match self {
+            Source::Example => Ok(example.to_owned()),
+            Source::Stdin => {
+                let mut input = String::new();
+                io::stdin()
+                    .read_to_string(&mut input)
+                    .map_err(|e| format!("can't read input from stdin: {e}"))?;
+                Ok(input)
+            }
+            Source::File(path) => read_file(path.clone()),
+            Source::Default => read_file(default_path(day)),
+        }?;
+        Ok(input.replace("\r\n", "\n"))
+    }
+
+    /// Opens the input to be read a bit at a time instead of all at once.
+    pub fn open(&self, day: u8, example: &'static str) -> Result<Box<dyn BufRead>, String> {
+        match self {
+            Source::Example => Ok(Box::new(example.as_bytes())),
+            Source::Stdin => Ok(Box::new(io::stdin().lock())),
+            Source::File(path) => open_file(path.clone()),
+            Source::Default => open_file(default_path(day)),
+        }
+    }
+}
+
+fn default_path(day: u8) -> PathBuf {
+    PathBuf::from(env!("CARGO_MANIFEST_DIR"))
+        .join("..")
+        .join(format!("day{day}"))
+        .join("src")
+        .join("input.txt")
+}
+
+fn read_file(path: PathBuf) -> Result<String, String> {
+    fs::read_to_string(&path).map_err(|e| format!("can't read input {}: {e}", path.display()))
+}
+
+fn open_file(path: PathBuf) -> Result<Box<dyn BufRead>, String> {
+    let file =
+        File::open(&path).map_err(|e| format!("can't open input {}: {e}", path.display()))?;
+    Ok(Box::new(BufReader::new(file)))
+}
@@ -0,0 +1,526 @@
+mod input;
+
+use std::{
+    error::Error,
+    fs::{self, File},
+    io::{self, BufWriter, Stdout},
+    path::{Path, PathBuf},
+    process::ExitCode,
+    str::FromStr,
+    time::Duration,
+};
+
+use canvas::{Animation, GifEncoder};
+use clap::{builder::RangedU64ValueParser, Args, Parser, Subcommand, ValueEnum};
+use common::{Answers, ParseError};
+use input::Source;
+
+struct Day {
+    number: u8,
+    example: &'static str,
+    solve: fn(&str) -> Result<Answers, ParseError>,
+}
+
+macro_rules! days {
+    ($($day:ident::$solution:ident = $number:literal),* $(,)?) => {
+        &[$(Day {
+            number: $number,
+            example: $day::EXAMPLE,
+            solve: common::solve::<$day::$solution>,
+        }),*]
+    };
+}
+
+const DAYS: &[Day] = days![
+    day1::Day1 = 1,
+    day2::Day2 = 2,
+    day3::Day3 = 3,
+    day4::Day4 = 4,
+    day5::Day5 = 5,
+    day6::Day6 = 6,
+    day7::Day7 = 7,
+    day8::Day8 = 8,
+    day9::Day9 = 9,
+    day10::Day10 = 10,
+    day11::Day11 = 11,
+    day12::Day12 = 12,
+    day13::Day13 = 13,
+    day14::Day14 = 14,
+    day15::Day15 = 15,
+    day16::Day16 = 16,
+    day17::Day17 = 17,
+    day18::Day18 = 18,
+    day19::Day19 = 19,
+    day20::Day20 = 20,
+    day21::Day21 = 21,
+    day22::Day22 = 22,
+    day23::Day23 = 23,
+    day24::Day24 = 24,
+    day25::Day25 = 25,
+];
+
+type Animate = fn(&str, u8, &mut Animation<Stdout>) -> Result<(), Box<dyn Error>>;
+
+/// The days that can be replayed in the terminal.
+const ANIMATIONS: &[(u8, Animate)] =
+    &[(5, day5::animate), (9, day9::animate), (22, day22::animate)];
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Selection {
+    All,
+    Day(u8),
+}
+
+impl FromStr for Selection {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        if s == "all" {
+            return Ok(Self::All);
+        }
+        let n: u8 = s
+            .parse()
+            .map_err(|_| format!("`{s}` is not a day number or `all`"))?;
+        if DAYS.iter().any(|d| d.number == n) {
+            Ok(Self::Day(n))
+        } else {
+            Err(format!("there is no solution for day {n}"))
+        }
+    }
+}
+
+#[derive(Debug, Parser)]
+#[command(about = "Advent of Code 2022 solutions")]
+struct Cli {
+    #[command(subcommand)]
+    command: Command,
+}
+
+#[derive(Debug, Args)]
+struct InputArgs {
+    /// Use the example from the puzzle text instead of the puzzle input
+    #[arg(long, conflicts_with = "input")]
+    example: bool,
+    /// Path to the puzzle input, `-` reads it from stdin [default: dayN/src/input.txt]
+    input: Option<PathBuf>,
+}
+
+impl InputArgs {
+    fn source(self) -> Source {
+        Source::new(self.example, self.input)
+    }
+}
+
+#[derive(Debug, Subcommand)]
+enum Command {
+    /// Run the solver of one day, or of every day with `all`
+    Run {
+        day: Selection,
+        #[command(flatten)]
+        input: InputArgs,
+    },
+    /// Replay the solution of a day in the terminal, days 5, 9 and 22 can be animated
+    Animate {
+        day: u8,
+        /// The part to replay
+        #[arg(long, default_value_t = 1, value_parser = clap::value_parser!(u8).range(1..=2))]
+        part: u8,
+        /// Milliseconds between two frames
+        #[arg(long, default_value_t = 100)]
+        delay: u64,
+        #[command(flatten)]
+        input: InputArgs,
+    },
+    /// Show the filesystem of day 7 rebuilt from the terminal output
+    Filesystem {
+        /// How to show it
+        #[arg(long, value_enum, default_value_t = FilesystemFormat::Tree)]
+        format: FilesystemFormat,
+        #[command(flatten)]
+        input: InputArgs,
+    },
+    /// Find the directories of day 7 to delete to make room for the update
+    ///
+    /// Finding the least to delete takes a bit of memory per byte to free, for every level
+    /// of directories: about 4 MB per level for the device.
+    Cleanup {
+        /// Size of the whole disk
+        #[arg(long, default_value_t = day7::Disk::DEVICE.capacity)]
+        capacity: u64,
+        /// Space that has to be free
+        #[arg(long, default_value_t = day7::Disk::DEVICE.required)]
+        required: u64,
+        #[command(flatten)]
+        input: InputArgs,
+    },
+    /// Play the rope of day 9 in the terminal, or save it as a GIF or as frames
+    Rope {
+        #[arg(long, default_value_t = 10)]
+        knots: usize,
+        /// Cells per character, by default the whole run fits in 80 by 40 characters
+        #[arg(long, conflicts_with = "follow", value_parser = RangedU64ValueParser::<u32>::new().range(1..))]
+        scale: Option<u32>,
+        /// Scroll a window of this many characters along with the head, like `80x40`
+        #[arg(long, value_parser = parse_window)]
+        follow: Option<(usize, usize)>,
+        /// Milliseconds between two frames
+        #[arg(long, default_value_t = 50)]
+        delay: u64,
+        /// Save the run as an animated GIF
+        #[arg(long)]
+        gif: Option<PathBuf>,
+        /// Save every frame as a numbered PNG in this directory
+        #[arg(long, conflicts_with = "gif")]
+        frames: Option<PathBuf>,
+        /// Pixels per cell in the GIF or the frames
+        #[arg(long, default_value_t = 4, value_parser = RangedU64ValueParser::<usize>::new().range(1..))]
+        pixel: usize,
+        #[command(flatten)]
+        input: InputArgs,
+    },
+    /// Show the trees of day 8 as a heatmap, with the sightlines of the best tree
+    Heatmap {
+        /// What to colour the trees by
+        #[arg(long, value_enum, default_value_t = HeatmapLayer::Heights)]
+        layer: HeatmapLayer,
+        /// Save an image instead, `.png` or `.ppm`
+        #[arg(long)]
+        output: Option<PathBuf>,
+        /// Pixels per tree in the image
+        #[arg(long, default_value_t = 4, value_parser = RangedU64ValueParser::<usize>::new().range(1..))]
+        scale: usize,
+        #[command(flatten)]
+        input: InputArgs,
+    },
+    /// Print a random forest like the input of day 8, to run day 8 on bigger ones
+    Forest {
+        #[arg(long, default_value_t = 99)]
+        width: usize,
+        #[arg(long, default_value_t = 99)]
+        height: usize,
+        #[arg(long, default_value_t = 0)]
+        seed: u64,
+    },
+    /// Find the first marker in the datastream of day 6, reading no more of it than needed
+    Marker {
+        /// How many different characters in a row make a marker
+        #[arg(long, default_value_t = 4, value_parser = RangedU64ValueParser::<usize>::new().range(1..))]
+        length: usize,
+        #[command(flatten)]
+        input: InputArgs,
+    },
+    /// Sum the badges of the day 3 elves for groups of any size
+    Badges {
+        /// How many elves share a badge
+        #[arg(long, default_value_t = 3, value_parser = RangedU64ValueParser::<usize>::new().range(1..))]
+        group_size: usize,
+        #[command(flatten)]
+        input: InputArgs,
+    },
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, ValueEnum)]
+enum FilesystemFormat {
+    /// Every file and directory with its size, like `tree`
+    Tree,
+    /// Every directory by size, like `du -h`
+    Du,
+    /// The whole hierarchy as JSON
+    Json,
+    /// Only the parts of the terminal output that don't add up, with their line numbers
+    Check,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, ValueEnum)]
+enum HeatmapLayer {
+    /// The height of every tree
+    Heights,
+    /// Which trees can be seen from outside of the forest
+    Visible,
+    /// The scenic score of every tree
+    Scenic,
+}
+
+fn parse_window(s: &str) -> Result<(usize, usize), String> {
+    let (width, height) = s
+        .split_once('x')
+        .ok_or_else(|| format!("`{s}` is not like `80x40`"))?;
+    let number = |n: &str| n.parse().map_err(|e| format!("`{n}`: {e}"));
+    Ok((number(width)?, number(height)?))
+}
+
+fn find_day(n: u8) -> &'static Day {
+    DAYS.iter().find(|d| d.number == n).unwrap()
+}
+
+fn run_day(day: &Day, source: &Source) -> Result<(), String> {
+    let input = source.read(day.number, day.example)?;
+
+    let answers = (day.solve)(&input).map_err(|e| format!("day {}: {e}", day.number))?;
+
+    println!("Day {}", day.number);
+    print_answer(1, &answers.part1);
+    print_answer(2, &answers.part2);
+    Ok(())
+}
+
+/// Multi-line answers, like a rendered screen, start on their own line.
+fn print_answer(part: u8, answer: &str) {
+    if answer.contains('\n') {
+        println!("Part {part}:\n{answer}");
+    } else {
+        println!("Part {part}: {answer}");
+    }
+}
+
+fn animate_day(n: u8, part: u8, delay: u64, source: &Source) -> Result<(), String> {
+    let (_, animate) = ANIMATIONS
+        .iter()
+        .find(|(d, _)| *d == n)
+        .ok_or_else(|| format!("day {n} can't be animated"))?;
+    let input = source.read(n, find_day(n).example)?;
+
+    let mut animation = Animation::new(io::stdout(), Duration::from_millis(delay));
+    animate(&input, part, &mut animation).map_err(|e| format!("day {n}: {e}"))
+}
+
+fn show_filesystem(format: FilesystemFormat, source: &Source) -> Result<(), String> {
+    let input = source.read(7, day7::EXAMPLE)?;
+    let (fs, warnings) = day7::replay(&input).map_err(|e| format!("day 7: {e}"))?;
+
+    let out = match format {
+        FilesystemFormat::Tree => fs.tree(),
+        FilesystemFormat::Du => fs.du(),
+        FilesystemFormat::Json => fs.to_json(),
+        FilesystemFormat::Check => {
+            for warning in &warnings {
+                println!("{warning}");
+            }
+            return match warnings.len() {
+                0 => Ok(()),
+                n => Err(format!("day 7: {n} problems in the terminal output")),
+            };
+        }
+    };
+    print!("{out}");
+    Ok(())
+}
+
+fn plan_cleanup(disk: day7::Disk, source: &Source) -> Result<(), String> {
+    let input = source.read(7, day7::EXAMPLE)?;
+    let (fs, _) = day7::replay(&input).map_err(|e| format!("day 7: {e}"))?;
+
+    let to_free = fs.to_free(disk);
+    if to_free == 0 {
+        println!("Nothing has to be deleted");
+        return Ok(());
+    }
+    let too_small = || format!("day 7: {} don't fit on the disk", disk.required);
+    let (path, size) = fs.smallest_dir_to_delete(disk).ok_or_else(too_small)?;
+    let plan = fs.cleanup_plan(disk).ok_or_else(too_small)?;
+    println!("To free: {to_free}");
+    println!("Smallest directory: {size}\t{path}");
+    println!("Least to delete:\n{plan}");
+    Ok(())
+}
+
+struct RopeArgs {
+    knots: usize,
+    scale: Option<u32>,
+    follow: Option<(usize, usize)>,
+    delay: u64,
+    gif: Option<PathBuf>,
+    frames: Option<PathBuf>,
+    pixel: usize,
+}
+
+fn play_rope(args: RopeArgs, source: &Source) -> Result<(), String> {
+    use common::Solution;
+    use day9::{Day9, Framing};
+
+    if args.knots == 0 {
+        return Err("a rope needs at least one knot".to_owned());
+    }
+    let input = source.read(9, day9::EXAMPLE)?;
+    let moves = Day9::parse(&input).map_err(|e| format!("day 9: {e}"))?;
+    let delay = Duration::from_millis(args.delay);
+    let create = |path: &Path| {
+        File::create(path)
+            .map(BufWriter::new)
+            .map_err(|e| format!("can't create {}: {e}", path.display()))
+    };
+    fn written(path: &Path) -> impl Fn(io::Error) -> String + '_ {
+        move |e| format!("can't write {}: {e}", path.display())
+    }
+
+    if let Some(path) = &args.gif {
+        let (min, max) = day9::bounds(&moves);
+        let cells = max - min + day9::V::new(1, 1);
+        // the size of a GIF is two u16s
+        let pixels = |cells: i32| {
+            (cells as usize)
+                .checked_mul(args.pixel)
+                .filter(|p| *p <= u16::MAX as usize)
+        };
+        let (Some(width), Some(height)) = (pixels(cells.x), pixels(cells.y)) else {
+            return Err(format!(
+                "a GIF of {} by {} cells of {} pixels is too big",
+                cells.x, cells.y, args.pixel
+            ));
+        };
+        let mut gif = GifEncoder::new(create(path)?, width, height, &day9::PALETTE, delay)
+            .map_err(written(path))?;
+        day9::frames(&moves, args.knots, args.pixel, |image| gif.frame(image))
+            .and_then(|_| gif.finish())
+            .map_err(written(path))?;
+        return Ok(());
+    }
+
+    if let Some(dir) = &args.frames {
+        fs::create_dir_all(dir).map_err(|e| format!("can't create {}: {e}", dir.display()))?;
+        let mut n = 0;
+        return day9::frames(&moves, args.knots, args.pixel, |image| {
+            n += 1;
+            let path = dir.join(format!("frame_{n:05}.png"));
+            let out = create(&path).map_err(io::Error::other)?;
+            image.write_png(out)
+        })
+        .map_err(written(dir));
+    }
+
+    let framing = match (args.scale, args.follow) {
+        (Some(scale), _) => Framing::Whole { scale },
+        (None, Some((width, height))) => Framing::Follow { width, height },
+        (None, None) => Framing::fit(&moves, 80, 40),
+    };
+    let mut animation = Animation::new(io::stdout(), delay);
+    day9::play(&moves, args.knots, framing, &mut animation).map_err(|e| e.to_string())
+}
+
+fn show_heatmap(
+    layer: HeatmapLayer,
+    output: Option<&Path>,
+    scale: usize,
+    source: &Source,
+) -> Result<(), String> {
+    use common::Solution;
+    use day8::{Day8, Layer};
+
+    let input = source.read(8, day8::EXAMPLE)?;
+    let forest = Day8::parse(&input).map_err(|e| format!("day 8: {e}"))?;
+    let layer = match layer {
+        HeatmapLayer::Heights => Layer::Heights,
+        HeatmapLayer::Visible => Layer::Visible,
+        HeatmapLayer::Scenic => Layer::Scenic,
+    };
+
+    let Some(path) = output else {
+        print!("{}", forest.heatmap(layer));
+        return Ok(());
+    };
+    let png = match path.extension().and_then(|e| e.to_str()) {
+        Some("png") => true,
+        Some("ppm") => false,
+        _ => return Err(format!("{} should end in .png or .ppm", path.display())),
+    };
+    let image = forest.image(layer).scaled(scale);
+    let file = File::create(path).map_err(|e| format!("can't create {}: {e}", path.display()))?;
+    let out = BufWriter::new(file);
+    let written = if png {
+        image.write_png(out)
+    } else {
+        image.write_ppm(out)
+    };
+    written.map_err(|e| format!("can't write {}: {e}", path.display()))
+}
+
+fn find_marker(length: usize, source: &Source) -> Result<(), String> {
+    let reader = source.open(6, day6::EXAMPLE)?;
+    match day6::read_marker(reader, length) {
+        Ok(Some(read)) => {
+            println!("{read}");
+            Ok(())
+        }
+        Ok(None) => Err(format!("day 6: no {length} different characters in a row")),
+        Err(e) => Err(format!("can't read input: {e}")),
+    }
+}
+
+fn sum_badges(group_size: usize, source: &Source) -> Result<(), String> {
+    let input = source.read(3, day3::EXAMPLE)?;
+    let rucksacks = day3::parse_rucksacks(&input).map_err(|e| format!("day 3: {e}"))?;
+    let sum = day3::badges(&rucksacks, group_size).map_err(|e| format!("day 3: {e}"))?;
+    println!("{sum}");
+    Ok(())
+}
+
+fn main() -> ExitCode {
+    let cli = Cli::parse();
+
+    let result = match cli.command {
+        Command::Run { day, input } => match (day, input.source()) {
+            (Selection::All, Source::File(_) | Source::Stdin) => {
+                Err("an input can only be given when running a single day".to_owned())
+            }
+            (Selection::All, source) => DAYS.iter().try_for_each(|d| run_day(d, &source)),
+            (Selection::Day(n), source) => run_day(find_day(n), &source),
+        },
+        Command::Animate {
+            day,
+            part,
+            delay,
+            input,
+        } => animate_day(day, part, delay, &input.source()),
+        Command::Filesystem { format, input } => show_filesystem(format, &input.source()),
+        Command::Cleanup {
+            capacity,
+            required,
+            input,
+        } => plan_cleanup(day7::Disk { capacity, required }, &input.source()),
+        Command::Rope {
+            knots,
+            scale,
+            follow,
+            delay,
+            gif,
+            frames,
+            pixel,
+            input,
+        } => play_rope(
+            RopeArgs {
+                knots,
+                scale,
+                follow,
+                delay,
+                gif,
+                frames,
+                pixel,
+            },
+            &input.source(),
+        ),
+        Command::Heatmap {
+            layer,
+            output,
+            scale,
+            input,
+        } => show_heatmap(layer, output.as_deref(), scale, &input.source()),
+        Command::Forest {
+            width,
+            height,
+            seed,
+        } => {
+            print!("{}", day8::Forest::generate(width, height, seed));
+            Ok(())
+        }
+        Command::Marker { length, input } => find_marker(length, &input.source()),
+        Command::Badges { group_size, input } => sum_badges(group_size, &input.source()),
+    };
+
+    match result {
+        Ok(()) => ExitCode::SUCCESS,
+        Err(e) => {
+            eprintln!("error: {e}");
+            ExitCode::FAILURE
+        }
+    }
+}
@@ -0,0 +1,67 @@
+use std::{
+    io::{self, Write},
+    thread,
+    time::Duration,
+};
+
+use crate::Display2D;
+
+/// Plays canvases one after the other, every frame is drawn over the previous one.
+pub struct Animation<W: Write> {
+    out: W,
+    delay: Duration,
+    last_height: usize,
+}
+
+impl<W: Write> Animation<W> {
+    pub fn new(out: W, delay: Duration) -> Self {
+        Self {
+            out,
+            delay,
+            last_height: 0,
+        }
+    }
+
+    /// Shows `frame` and waits for the delay before the next one can be shown.
+    pub fn frame(&mut self, frame: &Display2D) -> io::Result<()> {
+        if self.last_height != 0 {
+            // move the cursor back up to where the previous frame started
+            write!(self.out, "\x1b[{}A", self.last_height)?;
+        }
+        // and clear what's left of it, the new frame might be smaller
+        write!(self.out, "\x1b[J")?;
+        write!(self.out, "{frame}")?;
+        self.out.flush()?;
+        self.last_height = frame.height();
+
+        thread::sleep(self.delay);
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use geometry::Vec2;
+
+    use super::*;
+
+    #[test]
+    fn frames_draw_over_each_other() {
+        let mut out = Vec::new();
+        let mut animation = Animation::new(&mut out, Duration::ZERO);
+
+        let mut d = Display2D::new(2, 2);
+        d.draw(Vec2::new(0, 0), 'a');
+        animation.frame(&d).unwrap();
+        d.clear();
+        d.draw(Vec2::new(1, 1), 'b');
+        animation.frame(&d).unwrap();
+        animation.frame(&Display2D::new(1, 1)).unwrap();
+
+        // the first frame only clears below the cursor, the others go back up first
+        assert_eq!(
+            String::from_utf8(out).unwrap(),
+            "\x1b[Ja \n  \n\x1b[2A\x1b[J  \n b\n\x1b[2A\x1b[J \n"
+        );
+    }
+}
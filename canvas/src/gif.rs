@@ -0,0 +1,271 @@
+use std::{
+    collections::HashMap,
+    io::{self, Write},
+    time::Duration,
+};
+
+use crate::Image;
+
+/// Writes images as the frames of an animated GIF that loops forever.
+///
+/// GIFs have at most 256 colours, every pixel gets the closest colour of the palette.
+pub struct GifEncoder<W: Write> {
+    out: W,
+    width: usize,
+    height: usize,
+    palette: Vec<[u8; 3]>,
+    indices: HashMap<[u8; 3], u8>,
+    /// In hundredths of a second, the unit of GIFs
+    delay: u16,
+}
+
+impl<W: Write> GifEncoder<W> {
+    /// Starts the GIF, panics for an empty palette or one with more than 256 colours.
+    pub fn new(
+        mut out: W,
+        width: usize,
+        height: usize,
+        palette: &[[u8; 3]],
+        delay: Duration,
+    ) -> io::Result<Self> {
+        assert!(
+            (1..=256).contains(&palette.len()),
+            "a GIF has 1 to 256 colours"
+        );
+        // the colour table has a power of two entries, at least 2
+        let bits = palette.len().next_power_of_two().trailing_zeros().max(1);
+
+        out.write_all(b"GIF89a")?;
+        out.write_all(&(width as u16).to_le_bytes())?;
+        out.write_all(&(height as u16).to_le_bytes())?;
+        out.write_all(&[0x80 | 0x70 | (bits as u8 - 1), 0, 0])?;
+        for i in 0..1 << bits {
+            out.write_all(palette.get(i).unwrap_or(&[0; 3]))?;
+        }
+        // loop forever
+        out.write_all(b"\x21\xff\x0bNETSCAPE2.0\x03\x01\0\0\0")?;
+
+        Ok(Self {
+            out,
+            width,
+            height,
+            palette: palette.to_vec(),
+            indices: HashMap::new(),
+            delay: (delay.as_millis() / 10).min(u16::MAX as u128) as u16,
+        })
+    }
+
+    fn index(&mut self, rgb: [u8; 3]) -> u8 {
+        let palette = &self.palette;
+        *self.indices.entry(rgb).or_insert_with(|| {
+            let distance = |p: &[u8; 3]| {
+                (0..3)
+                    .map(|i| (p[i] as i32 - rgb[i] as i32).pow(2))
+                    .sum::<i32>()
+            };
+            (0..palette.len())
+                .min_by_key(|i| distance(&palette[*i]))
+                .unwrap() as u8
+        })
+    }
+
+    /// Adds `image` as the next frame, it has to be as big as the GIF.
+    pub fn frame(&mut self, image: &Image) -> io::Result<()> {
+        assert_eq!(
+            (image.width(), image.height()),
+            (self.width, self.height),
+            "frames have to be as big as the GIF"
+        );
+        let pixels: Vec<u8> = image.pixels().map(|p| self.index(p)).collect();
+
+        // how long to show the frame
+        self.out.write_all(b"\x21\xf9\x04\0")?;
+        self.out.write_all(&self.delay.to_le_bytes())?;
+        self.out.write_all(&[0, 0])?;
+
+        self.out.write_all(&[0x2c, 0, 0, 0, 0])?;
+        self.out.write_all(&(self.width as u16).to_le_bytes())?;
+        self.out.write_all(&(self.height as u16).to_le_bytes())?;
+        self.out.write_all(&[0])?;
+
+        let min_code_size = (self.palette.len().next_power_of_two().trailing_zeros()).max(2) as u8;
+        self.out.write_all(&[min_code_size])?;
+        for block in lzw(&pixels, min_code_size).chunks(255) {
+            self.out.write_all(&[block.len() as u8])?;
+            self.out.write_all(block)?;
+        }
+        self.out.write_all(&[0])
+    }
+
+    /// Ends the GIF and returns what it was written to.
+    pub fn finish(mut self) -> io::Result<W> {
+        self.out.write_all(&[0x3b])?;
+        self.out.flush()?;
+        Ok(self.out)
+    }
+}
+
+/// Writes codes of a growing width, starting at the lowest bit.
+#[derive(Default)]
+struct BitWriter {
+    bytes: Vec<u8>,
+    buffer: u32,
+    bits: u32,
+}
+
+impl BitWriter {
+    fn write(&mut self, code: u16, width: u32) {
+        self.buffer |= (code as u32) << self.bits;
+        self.bits += width;
+        while self.bits >= 8 {
+            self.bytes.push(self.buffer as u8);
+            self.buffer >>= 8;
+            self.bits -= 8;
+        }
+    }
+
+    fn finish(mut self) -> Vec<u8> {
+        if self.bits > 0 {
+            self.bytes.push(self.buffer as u8);
+        }
+        self.bytes
+    }
+}
+
+/// The LZW compression of GIFs, the table starts over when all 4096 codes are used.
+fn lzw(pixels: &[u8], min_code_size: u8) -> Vec<u8> {
+    const MAX_CODE: u16 = 4095;
+    let clear = 1u16 << min_code_size;
+    let end = clear + 1;
+
+    let mut out = BitWriter::default();
+    let mut width = min_code_size as u32 + 1;
+    let mut table: HashMap<(u16, u8), u16> = HashMap::new();
+    let mut last = end;
+    out.write(clear, width);
+
+    let mut pixels = pixels.iter();
+    let Some(first) = pixels.next() else {
+        out.write(end, width);
+        return out.finish();
+    };
+    let mut code = *first as u16;
+    for pixel in pixels {
+        if let Some(longer) = table.get(&(code, *pixel)) {
+            code = *longer;
+            continue;
+        }
+        out.write(code, width);
+        let emitted = code;
+        code = *pixel as u16;
+
+        last += 1;
+        if last == 1 << width {
+            width += 1;
+        }
+        if last == MAX_CODE {
+            out.write(clear, width);
+            table.clear();
+            width = min_code_size as u32 + 1;
+            last = end;
+        } else {
+            table.insert((emitted, *pixel), last);
+        }
+    }
+    out.write(code, width);
+    out.write(end, width);
+    out.finish()
+}
+
+#[cfg(test)]
+mod tests {
+    use geometry::Vec2;
+
+    use super::*;
+
+    /// Reads the codes back like a GIF decoder does
+    fn unlzw(bytes: &[u8], min_code_size: u8) -> Vec<u8> {
+        let clear = 1usize << min_code_size;
+        let singles = || (0..clear).map(|i| vec![i as u8]).collect::<Vec<_>>();
+        let mut table = singles();
+        let (mut width, mut bit, mut prev) = (min_code_size as usize + 1, 0, None::<usize>);
+        let mut out = Vec::new();
+        loop {
+            let code = (0..width)
+                .map(|i| ((bytes[(bit + i) / 8] >> ((bit + i) % 8)) as usize & 1) << i)
+                .sum::<usize>();
+            bit += width;
+            if code == clear {
+                table = singles();
+                // the clear and end codes take up two places
+                table.extend([vec![], vec![]]);
+                width = min_code_size as usize + 1;
+                prev = None;
+                continue;
+            }
+            if code == clear + 1 {
+                return out;
+            }
+            let Some(p) = prev else {
+                out.extend(&table[code]);
+                prev = Some(code);
+                continue;
+            };
+            let entry = if code < table.len() {
+                table[code].clone()
+            } else {
+                let mut e = table[p].clone();
+                e.push(table[p][0]);
+                e
+            };
+            out.extend(&entry);
+            if table.len() < 4096 {
+                let mut new = table[p].clone();
+                new.push(entry[0]);
+                table.push(new);
+                if table.len() == 1 << width && width < 12 {
+                    width += 1;
+                }
+            }
+            prev = Some(code);
+        }
+    }
+
+    #[test]
+    fn lzw_round_trip() {
+        let mut state = 7u32;
+        let noise: Vec<u8> = (0..50000)
+            .map(|i| {
+                state = state.wrapping_mul(1103515245).wrapping_add(12345);
+                if i % 3 == 0 {
+                    0
+                } else {
+                    (state >> 16) as u8 % 7
+                }
+            })
+            .collect();
+        for pixels in [vec![], vec![3], vec![1; 10000], noise] {
+            assert_eq!(unlzw(&lzw(&pixels, 3), 3), pixels);
+        }
+    }
+
+    #[test]
+    fn frames() {
+        let palette = [[0, 0, 0], [255, 0, 0], [0, 0, 255]];
+        let mut gif =
+            GifEncoder::new(Vec::new(), 3, 2, &palette, Duration::from_millis(50)).unwrap();
+        let mut image = Image::new(3, 2, [0, 0, 0]);
+        image.set(Vec2::new(2, 1), [250, 10, 10]);
+        gif.frame(&image).unwrap();
+        let bytes = gif.finish().unwrap();
+
+        assert!(bytes.starts_with(b"GIF89a\x03\0\x02\0\xf1"));
+        assert_eq!(bytes.last(), Some(&0x3b));
+        let data = bytes.windows(3).position(|w| w == [0, 0, 2]).unwrap() + 3;
+        let size = bytes[data] as usize;
+        assert_eq!(
+            unlzw(&bytes[data + 1..data + 1 + size], 2),
+            [0, 0, 0, 0, 0, 1]
+        );
+    }
+}
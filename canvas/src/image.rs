@@ -0,0 +1,154 @@
+use std::io::{self, Write};
+
+use geometry::{Grid, Vec2};
+
+/// A picture with one 24-bit colour per pixel, to be saved as PPM or PNG.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Image {
+    pixels: Grid<[u8; 3]>,
+}
+
+impl Image {
+    pub fn new(width: usize, height: usize, fill: [u8; 3]) -> Self {
+        Self {
+            pixels: Grid::new(width, height, fill),
+        }
+    }
+
+    pub fn width(&self) -> usize {
+        self.pixels.width()
+    }
+
+    pub fn height(&self) -> usize {
+        self.pixels.height()
+    }
+
+    pub fn get(&self, pos: Vec2<i32>) -> Option<[u8; 3]> {
+        self.pixels.get(pos).copied()
+    }
+
+    /// Every pixel row by row.
+    pub fn pixels(&self) -> impl Iterator<Item = [u8; 3]> + '_ {
+        self.pixels.iter().map(|(_, p)| *p)
+    }
+
+    /// Sets one pixel, anything outside of the image is cut off.
+    pub fn set(&mut self, pos: Vec2<i32>, rgb: [u8; 3]) {
+        if let Some(p) = self.pixels.get_mut(pos) {
+            *p = rgb;
+        }
+    }
+
+    /// The same image with every pixel drawn as a square of `factor` pixels.
+    #[must_use]
+    pub fn scaled(&self, factor: usize) -> Self {
+        let mut scaled = Self::new(self.width() * factor, self.height() * factor, [0; 3]);
+        for (pos, p) in scaled.pixels.iter_mut() {
+            *p = self.pixels[pos / factor as i32];
+        }
+        scaled
+    }
+
+    /// Writes the image as a binary PPM, the simplest format most viewers open.
+    pub fn write_ppm(&self, mut out: impl Write) -> io::Result<()> {
+        write!(out, "P6\n{} {}\n255\n", self.width(), self.height())?;
+        out.write_all(&self.pixels().flatten().collect::<Vec<_>>())
+    }
+
+    /// Writes the image as a PNG, without compressing it.
+    pub fn write_png(&self, mut out: impl Write) -> io::Result<()> {
+        out.write_all(b"\x89PNG\r\n\x1a\n")?;
+
+        let mut header = Vec::new();
+        header.extend((self.width() as u32).to_be_bytes());
+        header.extend((self.height() as u32).to_be_bytes());
+        // 8 bits per channel, RGB, no interlacing
+        header.extend([8, 2, 0, 0, 0]);
+        png_chunk(&mut out, b"IHDR", &header)?;
+
+        // every row starts with the filter type, 0 is none
+        let mut raw = Vec::with_capacity((self.width() * 3 + 1) * self.height());
+        for y in 0..self.height() {
+            raw.push(0);
+            raw.extend(self.pixels.row(y).iter().flatten());
+        }
+        png_chunk(&mut out, b"IDAT", &zlib_stored(&raw))?;
+        png_chunk(&mut out, b"IEND", &[])
+    }
+}
+
+fn png_chunk(out: &mut impl Write, kind: &[u8; 4], data: &[u8]) -> io::Result<()> {
+    out.write_all(&(data.len() as u32).to_be_bytes())?;
+    out.write_all(kind)?;
+    out.write_all(data)?;
+    let crc = crc32(kind.iter().chain(data));
+    out.write_all(&crc.to_be_bytes())
+}
+
+fn crc32<'a>(bytes: impl IntoIterator<Item = &'a u8>) -> u32 {
+    let mut crc = !0u32;
+    for b in bytes {
+        crc ^= *b as u32;
+        for _ in 0..8 {
+            crc = if crc & 1 == 1 {
+                (crc >> 1) ^ 0xedb88320
+            } else {
+                crc >> 1
+            };
+        }
+    }
+    !crc
+}
+
+/// A zlib stream of deflate blocks that are stored as they are.
+fn zlib_stored(data: &[u8]) -> Vec<u8> {
+    let mut out = vec![0x78, 0x01];
+    let mut blocks = data.chunks(u16::MAX as usize).peekable();
+    if blocks.peek().is_none() {
+        out.extend([1, 0, 0, 0xff, 0xff]);
+    }
+    while let Some(block) = blocks.next() {
+        let last = blocks.peek().is_none();
+        out.push(last as u8);
+        out.extend((block.len() as u16).to_le_bytes());
+        out.extend((!(block.len() as u16)).to_le_bytes());
+        out.extend(block);
+    }
+
+    let (mut a, mut b) = (1u32, 0u32);
+    for byte in data {
+        a = (a + *byte as u32) % 65521;
+        b = (b + a) % 65521;
+    }
+    out.extend(((b << 16) | a).to_be_bytes());
+    out
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn checksums() {
+        assert_eq!(crc32(b"IEND"), 0xae426082);
+        assert_eq!(crc32(b"123456789"), 0xcbf43926);
+    }
+
+    #[test]
+    fn formats() {
+        let mut image = Image::new(2, 1, [0, 0, 0]);
+        image.set(Vec2::new(1, 0), [255, 128, 0]);
+        let image = image.scaled(2);
+        assert_eq!(image.get(Vec2::new(3, 1)), Some([255, 128, 0]));
+
+        let mut ppm = Vec::new();
+        image.write_ppm(&mut ppm).unwrap();
+        assert!(ppm.starts_with(b"P6\n4 2\n255\n"));
+        assert_eq!(ppm.len(), 11 + 4 * 2 * 3);
+
+        let mut png = Vec::new();
+        image.write_png(&mut png).unwrap();
+        assert!(png.starts_with(b"\x89PNG\r\n\x1a\n\0\0\0\x0dIHDR\0\0\0\x04\0\0\0\x02"));
+        assert!(png.ends_with(b"\0\0\0\0IEND\xae\x42\x60\x82"));
+    }
+}
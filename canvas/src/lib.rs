@@ -0,0 +1,221 @@
+//! A character canvas to draw the puzzles on, in colour and animated in the terminal, and
+//! images to save them as files.
+
+use std::fmt::Display;
+
+use geometry::{Grid, Vec2};
+
+mod animation;
+mod gif;
+mod image;
+
+pub use animation::Animation;
+pub use gif::GifEncoder;
+pub use image::Image;
+
+/// One of the ANSI terminal colours.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Colour {
+    Black,
+    Red,
+    Green,
+    Yellow,
+    Blue,
+    Magenta,
+    Cyan,
+    White,
+    /// A 24-bit colour, not every terminal supports these.
+    Rgb(u8, u8, u8),
+}
+
+impl Colour {
+    /// A colour from blue for 0 over green to red for 1, for heatmaps.
+    pub fn heat(fraction: f64) -> Self {
+        let t = fraction.clamp(0.0, 1.0);
+        let channel = |centre: f64| (255.0 * (1.0 - 2.0 * (t - centre).abs()).max(0.0)) as u8;
+        Colour::Rgb(channel(1.0), channel(0.5), channel(0.0))
+    }
+
+    /// The colour as red, green and blue, for the named ones like most terminals show them.
+    pub fn rgb(&self) -> [u8; 3] {
+        match *self {
+            Colour::Black => [0, 0, 0],
+            Colour::Red => [205, 49, 49],
+            Colour::Green => [13, 188, 121],
+            Colour::Yellow => [229, 229, 16],
+            Colour::Blue => [36, 114, 200],
+            Colour::Magenta => [188, 63, 188],
+            Colour::Cyan => [17, 168, 205],
+            Colour::White => [229, 229, 229],
+            Colour::Rgb(r, g, b) => [r, g, b],
+        }
+    }
+
+    fn ansi(&self) -> String {
+        match self {
+            Colour::Black => "\x1b[30m".to_owned(),
+            Colour::Red => "\x1b[31m".to_owned(),
+            Colour::Green => "\x1b[32m".to_owned(),
+            Colour::Yellow => "\x1b[33m".to_owned(),
+            Colour::Blue => "\x1b[34m".to_owned(),
+            Colour::Magenta => "\x1b[35m".to_owned(),
+            Colour::Cyan => "\x1b[36m".to_owned(),
+            Colour::White => "\x1b[37m".to_owned(),
+            Colour::Rgb(r, g, b) => format!("\x1b[38;2;{r};{g};{b}m"),
+        }
+    }
+}
+
+const RESET: &str = "\x1b[0m";
+
+/// Which way a positive `y` goes on the canvas.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub enum YAxis {
+    /// `y = 0` is the top row, like the puzzle inputs are written.
+    #[default]
+    Down,
+    /// `y = 0` is the bottom row, like the chamber of day 17.
+    Up,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+struct Pixel {
+    c: char,
+    colour: Option<Colour>,
+}
+
+/// A fixed size canvas of characters, anything drawn outside of it is cut off.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Display2D {
+    pixels: Grid<Pixel>,
+    background: char,
+    y_axis: YAxis,
+}
+
+impl Display2D {
+    /// An empty canvas filled with spaces.
+    pub fn new(width: usize, height: usize) -> Self {
+        Self::with_background(width, height, ' ')
+    }
+
+    pub fn with_background(width: usize, height: usize, background: char) -> Self {
+        Self {
+            pixels: Grid::new(
+                width,
+                height,
+                Pixel {
+                    c: background,
+                    colour: None,
+                },
+            ),
+            background,
+            y_axis: YAxis::Down,
+        }
+    }
+
+    #[must_use]
+    pub fn y_axis(mut self, y_axis: YAxis) -> Self {
+        self.y_axis = y_axis;
+        self
+    }
+
+    pub fn width(&self) -> usize {
+        self.pixels.width()
+    }
+
+    pub fn height(&self) -> usize {
+        self.pixels.height()
+    }
+
+    fn pixel_mut(&mut self, pos: Vec2<i32>) -> Option<&mut Pixel> {
+        let pos = match self.y_axis {
+            YAxis::Down => pos,
+            YAxis::Up => Vec2::new(pos.x, self.height() as i32 - 1 - pos.y),
+        };
+        self.pixels.get_mut(pos)
+    }
+
+    pub fn draw(&mut self, pos: Vec2<i32>, c: char) {
+        if let Some(p) = self.pixel_mut(pos) {
+            *p = Pixel { c, colour: None };
+        }
+    }
+
+    pub fn draw_coloured(&mut self, pos: Vec2<i32>, c: char, colour: Colour) {
+        if let Some(p) = self.pixel_mut(pos) {
+            *p = Pixel {
+                c,
+                colour: Some(colour),
+            };
+        }
+    }
+
+    /// Fills the whole canvas with the background again.
+    pub fn clear(&mut self) {
+        let background = Pixel {
+            c: self.background,
+            colour: None,
+        };
+        for (_, p) in self.pixels.iter_mut() {
+            *p = background;
+        }
+    }
+}
+
+impl Display for Display2D {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        for row in self.pixels.rows() {
+            let mut current = None;
+            for p in row {
+                if p.colour != current {
+                    match p.colour {
+                        Some(colour) => write!(f, "{}", colour.ansi())?,
+                        None => write!(f, "{RESET}")?,
+                    }
+                    current = p.colour;
+                }
+                write!(f, "{}", p.c)?;
+            }
+            if current.is_some() {
+                write!(f, "{RESET}")?;
+            }
+            writeln!(f)?;
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn y_axis() {
+        let mut d = Display2D::with_background(2, 3, '.');
+        d.draw(Vec2::new(0, 0), 'a');
+        d.draw(Vec2::new(1, 2), 'b');
+        assert_eq!(d.to_string(), "a.\n..\n.b\n");
+
+        let mut d = Display2D::with_background(2, 3, '.').y_axis(YAxis::Up);
+        d.draw(Vec2::new(0, 0), 'a');
+        d.draw(Vec2::new(1, 2), 'b');
+        assert_eq!(d.to_string(), ".b\n..\na.\n");
+
+        // cut off on both sides of the flip
+        d.draw(Vec2::new(0, 3), 'c');
+        d.draw(Vec2::new(0, -1), 'c');
+        assert_eq!(d.to_string(), ".b\n..\na.\n");
+    }
+
+    #[test]
+    fn colours_and_clear() {
+        let mut d = Display2D::new(3, 1);
+        d.draw_coloured(Vec2::new(0, 0), 'r', Colour::Red);
+        d.draw_coloured(Vec2::new(1, 0), 'g', Colour::Rgb(0, 255, 0));
+        assert_eq!(d.to_string(), "\x1b[31mr\x1b[38;2;0;255;0mg\x1b[0m \n");
+
+        d.clear();
+        assert_eq!(d, Display2D::new(3, 1));
+        assert_eq!(d.to_string(), "   \n");
+    }
+}
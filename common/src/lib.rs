@@ -0,0 +1,54 @@
+use std::fmt::Display;
+
+mod parse;
+
+pub use parse::{parse_lines, ParseError, Scanner};
+
+/// The solution of one day: the input is parsed once and then shared by both parts.
+pub trait Solution {
+    type Input;
+    type Part1: Display;
+    type Part2: Display;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError>;
+
+    fn part1(input: &Self::Input) -> Self::Part1;
+
+    fn part2(input: &Self::Input) -> Self::Part2;
+}
+
+/// The answer of a part that has needs of its own, an input can parse and still be wrong for
+/// one of the parts. Shows the error in place of the answer, so the other part still runs.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Answer<T>(pub Result<T, ParseError>);
+
+impl<T> From<Result<T, ParseError>> for Answer<T> {
+    fn from(result: Result<T, ParseError>) -> Self {
+        Answer(result)
+    }
+}
+
+impl<T: Display> Display for Answer<T> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match &self.0 {
+            Ok(answer) => answer.fmt(f),
+            Err(e) => write!(f, "no answer, {e}"),
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Answers {
+    pub part1: String,
+    pub part2: String,
+}
+
+/// Parses `input` and runs both parts of `S` on it.
+pub fn solve<S: Solution>(input: &str) -> Result<Answers, ParseError> {
+    let input = S::parse(input)?;
+
+    Ok(Answers {
+        part1: S::part1(&input).to_string(),
+        part2: S::part2(&input).to_string(),
+    })
+}
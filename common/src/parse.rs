@@ -0,0 +1,350 @@
+use std::{error::Error, fmt::Display, str::FromStr};
+
+/// Why and where an input couldn't be parsed.
+///
+/// Lines and columns start at 1. A parser that only sees part of the input, like a single
+/// line, reports positions inside that part and its caller moves them with [`offset`].
+///
+/// [`offset`]: ParseError::offset
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ParseError {
+    pub line: usize,
+    pub column: usize,
+    pub expected: String,
+    pub found: String,
+}
+
+impl ParseError {
+    /// An error at the start of the input.
+    pub fn new(expected: impl Display, found: impl Display) -> Self {
+        Self {
+            line: 1,
+            column: 1,
+            expected: expected.to_string(),
+            found: found.to_string(),
+        }
+    }
+
+    /// Moves the error into a bigger input, in which the parsed part started after `lines`
+    /// whole lines and `columns` characters.
+    #[must_use]
+    pub fn offset(mut self, lines: usize, columns: usize) -> Self {
+        if self.line == 1 {
+            self.column += columns;
+        }
+        self.line += lines;
+        self
+    }
+
+    /// Moves the error down by `lines`, for parsers of a single line.
+    #[must_use]
+    pub fn offset_line(self, lines: usize) -> Self {
+        self.offset(lines, 0)
+    }
+}
+
+impl Display for ParseError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "line {}, column {}: expected {}, found {}",
+            self.line, self.column, self.expected, self.found
+        )
+    }
+}
+
+impl Error for ParseError {}
+
+/// A cursor over an input, the errors it returns point at where it currently is.
+#[derive(Debug, Clone)]
+pub struct Scanner<'a> {
+    s: &'a str,
+    pos: usize,
+}
+
+impl<'a> Scanner<'a> {
+    pub fn new(s: &'a str) -> Self {
+        Self { s, pos: 0 }
+    }
+
+    /// Runs `f` on a scanner over `s` and checks that all of `s` was used.
+    pub fn parse<T>(
+        s: &'a str,
+        f: impl FnOnce(&mut Self) -> Result<T, ParseError>,
+    ) -> Result<T, ParseError> {
+        let mut scanner = Self::new(s);
+        let value = f(&mut scanner)?;
+        scanner.end()?;
+        Ok(value)
+    }
+
+    /// The part of the input that isn't used yet.
+    pub fn rest(&self) -> &'a str {
+        &self.s[self.pos..]
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.rest().is_empty()
+    }
+
+    pub fn peek(&self) -> Option<char> {
+        self.rest().chars().next()
+    }
+
+    /// Takes a single character, whatever it is.
+    pub fn next_char(&mut self) -> Option<char> {
+        let c = self.peek()?;
+        self.pos += c.len_utf8();
+        Some(c)
+    }
+
+    /// The line and column the scanner is at.
+    pub fn position(&self) -> (usize, usize) {
+        let before = &self.s[..self.pos];
+        let line = before.matches('\n').count() + 1;
+        let line_start = before.rfind('\n').map_or(0, |i| i + 1);
+        (line, before[line_start..].chars().count() + 1)
+    }
+
+    /// An error at the current position, showing what is there instead.
+    pub fn error(&self, expected: impl Display) -> ParseError {
+        let (line, column) = self.position();
+        let rest = self.rest();
+        let found = match rest.chars().next() {
+            None | Some('\r' | '\n') => "end of line".to_owned(),
+            Some(c) if c.is_whitespace() => format!("`{c}`"),
+            Some(_) => {
+                let token: String = rest
+                    .chars()
+                    .take_while(|c| !c.is_whitespace())
+                    .take(16)
+                    .collect();
+                format!("`{token}`")
+            }
+        };
+        ParseError {
+            line,
+            column,
+            expected: expected.to_string(),
+            found,
+        }
+    }
+
+    /// Skips `tag` if the input continues with it.
+    pub fn try_tag(&mut self, tag: &str) -> bool {
+        if self.rest().starts_with(tag) {
+            self.pos += tag.len();
+            true
+        } else {
+            false
+        }
+    }
+
+    pub fn tag(&mut self, tag: &str) -> Result<(), ParseError> {
+        if self.try_tag(tag) {
+            Ok(())
+        } else {
+            Err(self.error(format_args!("`{tag}`")))
+        }
+    }
+
+    /// Skips the end of the current line.
+    pub fn newline(&mut self) -> Result<(), ParseError> {
+        if self.try_tag("\n") || self.try_tag("\r\n") {
+            Ok(())
+        } else {
+            Err(self.error("end of line"))
+        }
+    }
+
+    /// Skips spaces and tabs, but not line ends.
+    pub fn skip_spaces(&mut self) {
+        let rest = self.rest();
+        self.pos += rest.len() - rest.trim_start_matches([' ', '\t']).len();
+    }
+
+    /// Takes the characters while `pred` holds, possibly none.
+    pub fn take_while(&mut self, mut pred: impl FnMut(char) -> bool) -> &'a str {
+        let rest = self.rest();
+        let len = rest.find(|c| !pred(c)).unwrap_or(rest.len());
+        self.pos += len;
+        &rest[..len]
+    }
+
+    /// Takes everything that is left.
+    pub fn take_rest(&mut self) -> &'a str {
+        let rest = self.rest();
+        self.pos = self.s.len();
+        rest
+    }
+
+    /// Takes everything up to `tag` and skips the tag itself.
+    pub fn until(&mut self, tag: &str) -> Result<&'a str, ParseError> {
+        let rest = self.rest();
+        let line = &rest[..rest.find('\n').unwrap_or(rest.len())];
+        match line.find(tag) {
+            Some(len) => {
+                self.pos += len + tag.len();
+                Ok(&rest[..len])
+            }
+            None => {
+                let mut at_end = self.clone();
+                at_end.pos += line.len();
+                Err(at_end.error(format_args!("`{tag}`")))
+            }
+        }
+    }
+
+    /// Takes a word made of letters.
+    pub fn word(&mut self) -> Result<&'a str, ParseError> {
+        let word = self.take_while(|c| c.is_alphabetic());
+        if word.is_empty() {
+            Err(self.error("a word"))
+        } else {
+            Ok(word)
+        }
+    }
+
+    /// Takes an optionally signed decimal number.
+    pub fn number<T: FromStr>(&mut self) -> Result<T, ParseError> {
+        let start = self.clone();
+        let rest = self.rest();
+        let sign = usize::from(rest.starts_with(['-', '+']));
+        let digits = rest[sign..]
+            .find(|c: char| !c.is_ascii_digit())
+            .unwrap_or(rest.len() - sign);
+        if digits == 0 {
+            return Err(self.error("a number"));
+        }
+        self.pos += sign + digits;
+        rest[..sign + digits]
+            .parse()
+            .map_err(|_| start.error("a number that fits"))
+    }
+
+    /// Takes a single decimal digit.
+    pub fn digit(&mut self) -> Result<u32, ParseError> {
+        match self.peek().and_then(|c| c.to_digit(10)) {
+            Some(d) => {
+                self.pos += 1;
+                Ok(d)
+            }
+            None => Err(self.error("a digit")),
+        }
+    }
+
+    /// Skips the first of `options` the input continues with and returns its value.
+    pub fn one_of<T: Clone>(&mut self, options: &[(&str, T)]) -> Result<T, ParseError> {
+        for (tag, value) in options {
+            if self.try_tag(tag) {
+                return Ok(value.clone());
+            }
+        }
+        let tags: Vec<String> = options.iter().map(|(t, _)| format!("`{t}`")).collect();
+        let expected = match tags.split_last() {
+            Some((last, [])) => last.clone(),
+            Some((last, rest)) => format!("{} or {last}", rest.join(", ")),
+            None => "nothing".to_owned(),
+        };
+        Err(self.error(expected))
+    }
+
+    /// Checks that the whole input has been used.
+    pub fn end(&self) -> Result<(), ParseError> {
+        if self.is_empty() {
+            Ok(())
+        } else {
+            Err(self.error("end of line"))
+        }
+    }
+}
+
+/// Parses every line of `input` with `f`, errors point at the line they come from.
+pub fn parse_lines<'a, T>(
+    input: &'a str,
+    mut f: impl FnMut(&mut Scanner<'a>) -> Result<T, ParseError>,
+) -> Result<Vec<T>, ParseError> {
+    input
+        .lines()
+        .enumerate()
+        .map(|(i, l)| Scanner::parse(l, &mut f).map_err(|e| e.offset_line(i)))
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn offset() {
+        let error = ParseError::new("a", "b");
+        assert_eq!((error.line, error.column), (1, 1));
+        let moved = error.clone().offset(2, 5);
+        assert_eq!((moved.line, moved.column), (3, 6));
+
+        // only the first line of the part starts after the columns
+        let below = ParseError { line: 2, ..error };
+        let moved = below.offset(2, 5);
+        assert_eq!((moved.line, moved.column), (4, 1));
+
+        let error = parse_lines("1\n2\nx", |s| s.number::<u8>()).unwrap_err();
+        assert_eq!((error.line, error.column), (3, 1));
+        assert_eq!(
+            error.to_string(),
+            "line 3, column 1: expected a number, found `x`"
+        );
+    }
+
+    #[test]
+    fn until() {
+        let mut s = Scanner::new("name: value\nnext: line");
+        assert_eq!(s.until(": "), Ok("name"));
+        assert_eq!(s.rest(), "value\nnext: line");
+
+        // the tag has to be on the same line
+        let error = s.until(": ").unwrap_err();
+        assert_eq!((error.line, error.column), (1, 12));
+        assert_eq!(
+            (error.expected.as_str(), error.found.as_str()),
+            ("`: `", "end of line")
+        );
+    }
+
+    #[test]
+    fn number() {
+        assert_eq!(Scanner::parse("-12", |s| s.number::<i32>()), Ok(-12));
+        assert_eq!(Scanner::parse("+7", |s| s.number::<i32>()), Ok(7));
+
+        let mut s = Scanner::new("42abc");
+        assert_eq!(s.number::<u32>(), Ok(42));
+        assert_eq!(s.rest(), "abc");
+
+        let error = Scanner::parse("-", |s| s.number::<i32>()).unwrap_err();
+        assert_eq!(error.expected, "a number");
+
+        let error = Scanner::parse("x 300", |s| {
+            s.tag("x ")?;
+            s.number::<u8>()
+        })
+        .unwrap_err();
+        assert_eq!(
+            (error.column, error.expected.as_str()),
+            (3, "a number that fits")
+        );
+        let error = Scanner::parse("-1", |s| s.number::<u8>()).unwrap_err();
+        assert_eq!(error.expected, "a number that fits");
+    }
+
+    #[test]
+    fn one_of() {
+        let mut s = Scanner::new("b");
+        assert_eq!(s.one_of(&[("a", 1), ("b", 2)]), Ok(2));
+
+        let expected =
+            |options: &[(&str, u8)]| Scanner::new("x").one_of(options).unwrap_err().expected;
+        assert_eq!(expected(&[("a", 1)]), "`a`");
+        assert_eq!(expected(&[("a", 1), ("b", 2)]), "`a` or `b`");
+        assert_eq!(expected(&[("a", 1), ("b", 2), ("c", 3)]), "`a`, `b` or `c`");
+        assert_eq!(expected(&[]), "nothing");
+    }
+}
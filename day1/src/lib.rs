@@ -0,0 +1,58 @@
+use common::{ParseError, Scanner, Solution};
+
+pub const EXAMPLE: &str = include_str!("./example.txt");
+
+/// The calories carried by the `n` elves that carry the most, together
+pub fn top(elves: &[u64], n: usize) -> u64 {
+    let mut elves = elves.to_vec();
+    elves.sort_unstable_by(|a, b| b.cmp(a));
+    elves.iter().take(n).sum()
+}
+
+pub struct Day1;
+
+impl Solution for Day1 {
+    type Input = Vec<u64>;
+    type Part1 = u64;
+    type Part2 = u64;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        let mut elves = vec![0];
+
+        for (i, l) in input.lines().enumerate() {
+            if l.is_empty() {
+                elves.push(0);
+                continue;
+            }
+            let calories: u64 = Scanner::parse(l, |s| s.number()).map_err(|e| e.offset_line(i))?;
+            *elves.last_mut().unwrap() += calories;
+        }
+
+        Ok(elves)
+    }
+
+    fn part1(input: &Self::Input) -> Self::Part1 {
+        top(input, 1)
+    }
+
+    fn part2(input: &Self::Input) -> Self::Part2 {
+        top(input, 3)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn part1_example() {
+        let input = Day1::parse(EXAMPLE).unwrap();
+        assert_eq!(Day1::part1(&input), 24000);
+    }
+
+    #[test]
+    fn part2_example() {
+        let input = Day1::parse(EXAMPLE).unwrap();
+        assert_eq!(Day1::part2(&input), 45000);
+    }
+}
@@ -0,0 +1,317 @@
+mod ocr;
+
+use std::str::FromStr;
+
+use canvas::Display2D;
+use common::{parse_lines, ParseError, Scanner, Solution};
+use geometry::{Grid, Vec2};
+
+pub use ocr::{read_screen, render_text, GLYPH_HEIGHT, GLYPH_WIDTH};
+
+pub const EXAMPLE: &str = include_str!("./example.txt");
+
+/// The registers of the CPU
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Registers {
+    pub x: i64,
+}
+
+impl Default for Registers {
+    fn default() -> Self {
+        Self { x: 1 }
+    }
+}
+
+/// Something the CPU can run, a new instruction only has to say how long it takes and what
+/// it does
+pub trait Op {
+    /// How many cycles the instruction takes
+    fn cycles(&self) -> u32;
+
+    /// Changes the registers at the end of the last cycle
+    fn execute(&self, registers: &mut Registers);
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum Instruction {
+    Noop,
+    Addx(i64),
+}
+
+impl Op for Instruction {
+    fn cycles(&self) -> u32 {
+        match self {
+            Instruction::Noop => 1,
+            Instruction::Addx(_) => 2,
+        }
+    }
+
+    fn execute(&self, registers: &mut Registers) {
+        match self {
+            Instruction::Noop => {}
+            Instruction::Addx(n) => registers.x += n,
+        }
+    }
+}
+
+/// Runs a program one cycle at a time, yielding the cycle starting at 1 and the value of `x`
+/// during it
+#[derive(Debug, Clone)]
+pub struct Cpu<'a, I> {
+    program: &'a [I],
+    next: usize,
+    /// Cycles until the current instruction is done, 0 if there is none
+    left: u32,
+    cycle: u64,
+    registers: Registers,
+}
+
+impl<'a, I: Op> Cpu<'a, I> {
+    pub fn new(program: &'a [I]) -> Self {
+        Self {
+            program,
+            next: 0,
+            left: 0,
+            cycle: 0,
+            registers: Registers::default(),
+        }
+    }
+
+    /// The registers after the cycles that have run
+    pub fn registers(&self) -> Registers {
+        self.registers
+    }
+}
+
+impl<I: Op> Iterator for Cpu<'_, I> {
+    type Item = (u64, i64);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        while self.left == 0 {
+            let op = self.program.get(self.next)?;
+            self.left = op.cycles();
+            if self.left == 0 {
+                op.execute(&mut self.registers);
+                self.next += 1;
+            }
+        }
+
+        self.cycle += 1;
+        let during = (self.cycle, self.registers.x);
+        self.left -= 1;
+        if self.left == 0 {
+            self.program[self.next].execute(&mut self.registers);
+            self.next += 1;
+        }
+        Some(during)
+    }
+}
+
+/// The cycles to look at the signal during: `first`, then `every` cycles up to `last`
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Probes {
+    pub first: u64,
+    pub every: u64,
+    pub last: u64,
+}
+
+impl Probes {
+    /// The cycles 20, 60, 100, 140, 180 and 220
+    pub const PUZZLE: Probes = Probes {
+        first: 20,
+        every: 40,
+        last: 220,
+    };
+
+    pub fn contains(&self, cycle: u64) -> bool {
+        (self.first..=self.last).contains(&cycle) && (cycle - self.first).is_multiple_of(self.every)
+    }
+}
+
+/// The sum of the cycle times `x` at every probe
+pub fn signal_strength<I: Op>(program: &[I], probes: Probes) -> i64 {
+    Cpu::new(program)
+        .take_while(|(cycle, _)| *cycle <= probes.last)
+        .filter(|(cycle, _)| probes.contains(*cycle))
+        .map(|(cycle, x)| cycle as i64 * x)
+        .sum()
+}
+
+impl Instruction {
+    fn scan(s: &mut Scanner<'_>) -> Result<Self, ParseError> {
+        if s.one_of(&[("noop", true), ("addx ", false)])? {
+            Ok(Self::Noop)
+        } else {
+            Ok(Self::Addx(s.number()?))
+        }
+    }
+}
+
+impl FromStr for Instruction {
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Scanner::parse(s, Self::scan)
+    }
+}
+
+pub const SCREEN_WIDTH: usize = 40;
+pub const SCREEN_HEIGHT: usize = 6;
+
+/// What the CRT shows after `program`, a pixel is lit if the sprite at `x` covers it while it
+/// is drawn
+pub fn crt<I: Op>(program: &[I]) -> Grid<bool> {
+    let mut screen = Grid::new(SCREEN_WIDTH, SCREEN_HEIGHT, false);
+    for (cycle, sprite) in Cpu::new(program).take(SCREEN_WIDTH * SCREEN_HEIGHT) {
+        let i = cycle as usize - 1;
+        let pos = Vec2::new((i % SCREEN_WIDTH) as i32, (i / SCREEN_WIDTH) as i32);
+        screen[pos] = (sprite - pos.x as i64).abs() <= 1;
+    }
+    screen
+}
+
+/// The screen with `#` for every lit pixel
+pub fn draw(screen: &Grid<bool>) -> Display2D {
+    let mut display = Display2D::new(screen.width(), screen.height());
+    for (pos, lit) in screen.iter() {
+        if *lit {
+            display.draw(pos, '#');
+        }
+    }
+    display
+}
+
+pub struct Day10;
+
+impl Solution for Day10 {
+    type Input = Vec<Instruction>;
+    type Part1 = i64;
+    type Part2 = String;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        parse_lines(input, Instruction::scan)
+    }
+
+    fn part1(input: &Self::Input) -> Self::Part1 {
+        signal_strength(input, Probes::PUZZLE)
+    }
+
+    /// The letters on the screen, or the screen itself if it doesn't show letters
+    fn part2(input: &Self::Input) -> Self::Part2 {
+        let screen = crt(input);
+        read_screen(&screen).unwrap_or_else(|| draw(&screen).to_string())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn part1_example() {
+        let input = Day10::parse(EXAMPLE).unwrap();
+        assert_eq!(Day10::part1(&input), 13140);
+    }
+
+    #[test]
+    fn part2_example() {
+        let input = Day10::parse(EXAMPLE).unwrap();
+        let screen = Day10::part2(&input);
+        assert_eq!(
+            screen.lines().map(str::trim_end).collect::<Vec<_>>(),
+            [
+                "##  ##  ##  ##  ##  ##  ##  ##  ##  ##",
+                "###   ###   ###   ###   ###   ###   ###",
+                "####    ####    ####    ####    ####",
+                "#####     #####     #####     #####",
+                "######      ######      ######      ####",
+                "#######       #######       #######",
+            ]
+        );
+    }
+
+    #[test]
+    fn letters() {
+        let screen = render_text("RKAZAJBR").unwrap();
+        assert_eq!(read_screen(&screen).as_deref(), Some("RKAZAJBR"));
+        assert_eq!(render_text("Q"), None);
+
+        // a program that draws them, `addx` keeps the sprite in place for two pixels
+        let lit = |pos: Vec2<i32>| screen.get(pos).copied().unwrap_or(false);
+        let mut sprites = Vec::new();
+        for y in 0..SCREEN_HEIGHT as i32 {
+            for left in (0..SCREEN_WIDTH as i32).step_by(2) {
+                sprites.push(
+                    match (lit(Vec2::new(left, y)), lit(Vec2::new(left + 1, y))) {
+                        (true, true) => left + 1,
+                        (true, false) => left - 1,
+                        (false, true) => left + 2,
+                        (false, false) => -5,
+                    } as i64,
+                );
+            }
+        }
+        assert_eq!(sprites[0], 1, "the sprite starts at 1");
+        let mut program: Vec<_> = sprites
+            .windows(2)
+            .map(|w| Instruction::Addx(w[1] - w[0]))
+            .collect();
+        program.push(Instruction::Addx(0));
+        assert_eq!(Day10::part2(&program), "RKAZAJBR");
+
+        let input = Day10::parse(EXAMPLE).unwrap();
+        assert_eq!(read_screen(&crt(&input)), None);
+    }
+
+    #[test]
+    fn cycles() {
+        let program = Day10::parse("noop\naddx 3\naddx -5\n").unwrap();
+        let mut cpu = Cpu::new(&program);
+        let cycles: Vec<_> = cpu.by_ref().collect();
+        assert_eq!(cycles, [(1, 1), (2, 1), (3, 1), (4, 4), (5, 4)]);
+        assert_eq!(cpu.registers().x, -1);
+
+        let input = Day10::parse(EXAMPLE).unwrap();
+        let probes = Probes {
+            first: 20,
+            every: 40,
+            last: 60,
+        };
+        assert_eq!(signal_strength(&input, probes), 420 + 1140);
+        assert!(Probes::PUZZLE.contains(100) && !Probes::PUZZLE.contains(260));
+    }
+
+    /// An instruction that isn't in the puzzle, to add to the ones that are
+    enum Extended {
+        Base(Instruction),
+        /// Doubles `x` in three cycles
+        Double,
+    }
+
+    impl Op for Extended {
+        fn cycles(&self) -> u32 {
+            match self {
+                Extended::Base(i) => i.cycles(),
+                Extended::Double => 3,
+            }
+        }
+
+        fn execute(&self, registers: &mut Registers) {
+            match self {
+                Extended::Base(i) => i.execute(registers),
+                Extended::Double => registers.x *= 2,
+            }
+        }
+    }
+
+    #[test]
+    fn new_instruction() {
+        let program = [
+            Extended::Base(Instruction::Addx(2)),
+            Extended::Double,
+            Extended::Base(Instruction::Noop),
+        ];
+        let xs: Vec<i64> = Cpu::new(&program).map(|(_, x)| x).collect();
+        assert_eq!(xs, [1, 1, 3, 3, 3, 6]);
+    }
+}
@@ -0,0 +1,72 @@
+use geometry::{Grid, Vec2};
+
+/// The letters the CRT can show, 4 pixels wide and 6 high
+const FONT: [(char, &str); 18] = [
+    ('A', ".##.#..##..######..##..#"),
+    ('B', "###.#..####.#..##..####."),
+    ('C', ".##.#..##...#...#..#.##."),
+    ('E', "#####...###.#...#...####"),
+    ('F', "#####...###.#...#...#..."),
+    ('G', ".##.#..##...#.###..#.###"),
+    ('H', "#..##..######..##..##..#"),
+    ('I', ".###..#...#...#...#..###"),
+    ('J', "..##...#...#...##..#.##."),
+    ('K', "#..##.#.##..#.#.#.#.#..#"),
+    ('L', "#...#...#...#...#...####"),
+    ('O', ".##.#..##..##..##..#.##."),
+    ('P', "###.#..##..####.#...#..."),
+    ('R', "###.#..##..####.#.#.#..#"),
+    ('S', ".####...#....##....####."),
+    ('U', "#..##..##..##..##..#.##."),
+    ('Y', "#...#....#.#..#...#...#."),
+    ('Z', "####...#..#..#..#...####"),
+];
+
+pub const GLYPH_WIDTH: usize = 4;
+pub const GLYPH_HEIGHT: usize = 6;
+/// A glyph and the empty column after it
+const STRIDE: usize = GLYPH_WIDTH + 1;
+
+/// Reads the letters on a screen, `None` if anything on it isn't a letter of the font
+pub fn read_screen(screen: &Grid<bool>) -> Option<String> {
+    let full_glyphs = matches!(screen.width() % STRIDE, 0 | GLYPH_WIDTH);
+    if screen.height() != GLYPH_HEIGHT || screen.width() == 0 || !full_glyphs {
+        return None;
+    }
+
+    let mut text = String::new();
+    for left in (0..screen.width()).step_by(STRIDE) {
+        let lit = |x: usize, y: usize| screen[Vec2::new((left + x) as i32, y as i32)];
+        let mut glyph = String::new();
+        for y in 0..GLYPH_HEIGHT {
+            for x in 0..GLYPH_WIDTH {
+                glyph.push(if lit(x, y) { '#' } else { '.' });
+            }
+        }
+        let gap_lit =
+            left + GLYPH_WIDTH < screen.width() && (0..GLYPH_HEIGHT).any(|y| lit(GLYPH_WIDTH, y));
+        if gap_lit {
+            return None;
+        }
+        let (c, _) = FONT.iter().find(|(_, pixels)| *pixels == glyph)?;
+        text.push(*c);
+    }
+    Some(text)
+}
+
+/// Draws `text` like the CRT would, for the letters of the font
+pub fn render_text(text: &str) -> Option<Grid<bool>> {
+    let width = (text.chars().count() * STRIDE).saturating_sub(1);
+    let mut screen = Grid::new(width, GLYPH_HEIGHT, false);
+    for (i, c) in text.chars().enumerate() {
+        let (_, pixels) = FONT.iter().find(|(letter, _)| *letter == c)?;
+        for (j, p) in pixels.chars().enumerate() {
+            let pos = Vec2::new(
+                (i * STRIDE + j % GLYPH_WIDTH) as i32,
+                (j / GLYPH_WIDTH) as i32,
+            );
+            screen[pos] = p == '#';
+        }
+    }
+    Some(screen)
+}
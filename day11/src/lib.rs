@@ -0,0 +1,193 @@
+pub const EXAMPLE: &str = include_str!("./example.txt");
+
+use common::{ParseError, Scanner, Solution};
+
+type Item = u64;
+
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Default)]
+enum Operation {
+    #[default]
+    Square,
+    Add(u64),
+    Mul(u64),
+}
+
+#[derive(Debug, Clone, Default)]
+pub struct Monkey {
+    items: Vec<Item>,
+    operation: Operation,
+    div_test: u64,
+    on_true: usize,
+    on_false: usize,
+
+    total_inspects: u64,
+}
+
+fn mod_pow(mut base: u64, mut exp: u64, modulus: u64) -> u64 {
+    if modulus == 1 {
+        return 0;
+    }
+    let mut result = 1;
+    base %= modulus;
+    while exp > 0 {
+        if exp % 2 == 1 {
+            result = result * base % modulus;
+        }
+        exp >>= 1;
+        base = base * base % modulus
+    }
+    result
+}
+
+fn simulate(monkeys: &[Monkey], rounds: usize, relief: bool) -> u64 {
+    let mut monkeys = monkeys.to_vec();
+
+    let mut lcm = 1;
+    for monkey in &monkeys {
+        lcm = num::integer::lcm(lcm, monkey.div_test);
+    }
+
+    for _ in 0..rounds {
+        for i in 0..monkeys.len() {
+            let mut throws = Vec::new();
+
+            let monkey = &mut monkeys[i];
+
+            for item in monkey.items.iter() {
+                let item = if relief {
+                    let item = match monkey.operation {
+                        Operation::Square => item * item,
+                        Operation::Add(n) => item + n,
+                        Operation::Mul(n) => item * n,
+                    };
+                    item / 3
+                } else {
+                    let item = match monkey.operation {
+                        Operation::Square => mod_pow(*item, 2, lcm),
+                        Operation::Add(n) => item + n,
+                        Operation::Mul(n) => item * n,
+                    };
+                    item % lcm
+                };
+
+                monkey.total_inspects += 1;
+
+                if item % monkey.div_test == 0 {
+                    throws.push((item, monkey.on_true));
+                } else {
+                    throws.push((item, monkey.on_false));
+                }
+            }
+
+            monkey.items.clear();
+            for (item, monkey_i) in throws {
+                monkeys[monkey_i].items.push(item);
+            }
+        }
+    }
+
+    let mut inspects = monkeys.iter().map(|m| m.total_inspects).collect::<Vec<_>>();
+    inspects.sort();
+
+    let mut inspects = inspects.iter().rev();
+    inspects.next().unwrap() * inspects.next().unwrap()
+}
+
+pub struct Day11;
+
+impl Solution for Day11 {
+    type Input = Vec<Monkey>;
+    type Part1 = u64;
+    type Part2 = u64;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        let mut targets = Vec::new();
+
+        let monkeys = Scanner::parse(input.trim_end(), |s| {
+            let mut monkeys = Vec::new();
+            loop {
+                let mut monkey = Monkey::default();
+
+                s.tag("Monkey ")?;
+                s.number::<usize>()?;
+                s.tag(":")?;
+                s.newline()?;
+
+                s.tag("  Starting items: ")?;
+                monkey.items.push(s.number()?);
+                while s.try_tag(", ") {
+                    monkey.items.push(s.number()?);
+                }
+                s.newline()?;
+
+                s.tag("  Operation: new = old ")?;
+                monkey.operation = if s.try_tag("* old") {
+                    Operation::Square
+                } else if s.one_of(&[("+ ", true), ("* ", false)])? {
+                    Operation::Add(s.number()?)
+                } else {
+                    Operation::Mul(s.number()?)
+                };
+                s.newline()?;
+
+                s.tag("  Test: divisible by ")?;
+                monkey.div_test = s.number()?;
+                s.newline()?;
+
+                s.tag("    If true: throw to monkey ")?;
+                targets.push(s.clone());
+                monkey.on_true = s.number()?;
+                s.newline()?;
+
+                s.tag("    If false: throw to monkey ")?;
+                targets.push(s.clone());
+                monkey.on_false = s.number()?;
+
+                monkeys.push(monkey);
+                if s.is_empty() {
+                    break;
+                }
+                s.newline()?;
+                s.newline()?;
+            }
+            Ok(monkeys)
+        })?;
+
+        // a monkey can only throw to the monkeys that are there
+        for (at, monkey) in targets
+            .iter()
+            .zip(monkeys.iter().flat_map(|m| [m.on_true, m.on_false]))
+        {
+            if monkey >= monkeys.len() {
+                return Err(at.error(format_args!("a monkey below {}", monkeys.len())));
+            }
+        }
+
+        Ok(monkeys)
+    }
+
+    fn part1(input: &Self::Input) -> Self::Part1 {
+        simulate(input, 20, true)
+    }
+
+    fn part2(input: &Self::Input) -> Self::Part2 {
+        simulate(input, 10000, false)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn part1_example() {
+        let input = Day11::parse(EXAMPLE).unwrap();
+        assert_eq!(Day11::part1(&input), 10605);
+    }
+
+    #[test]
+    fn part2_example() {
+        let input = Day11::parse(EXAMPLE).unwrap();
+        assert_eq!(Day11::part2(&input), 2713310158);
+    }
+}
@@ -0,0 +1,136 @@
+use std::str::FromStr;
+
+use common::{ParseError, Solution};
+use geometry::{Grid, Vec2};
+
+pub const EXAMPLE: &str = include_str!("./example.txt");
+
+type V = Vec2<i32>;
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct Map {
+    heights: Grid<i32>,
+    start: V,
+    end: V,
+}
+
+impl FromStr for Map {
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let chars = Grid::parse_with(s, |c| match c {
+            'a'..='z' | 'S' | 'E' => Ok(c),
+            c => Err(ParseError::new(
+                "a height from `a` to `z`",
+                format_args!("`{c}`"),
+            )),
+        })?;
+
+        let missing = |square| {
+            ParseError::new(format_args!("a square marked `{square}`"), "end of input")
+                .offset_line(s.lines().count())
+        };
+        let start = chars.position(|c| *c == 'S').ok_or_else(|| missing('S'))?;
+        let end = chars.position(|c| *c == 'E').ok_or_else(|| missing('E'))?;
+        let heights = chars.map(|c| {
+            let c = match c {
+                'S' => 'a',
+                'E' => 'z',
+                c => *c,
+            };
+            c as i32 - 'a' as i32
+        });
+
+        Ok(Self {
+            heights,
+            start,
+            end,
+        })
+    }
+}
+
+impl Map {
+    fn neighbors_inv(&self, v: V) -> impl Iterator<Item = V> + '_ {
+        let height = self.heights[v];
+        self.heights
+            .neighbours(v)
+            .filter(move |(_, new_height)| height - 1 <= **new_height)
+            .map(|(new_v, _)| new_v)
+    }
+}
+
+/// Finds the length of the shortest path from every cell to `start`, walking backwards
+fn do_a_star_from(start: V, map: &Map) -> Grid<Option<u32>> {
+    let mut visited = map.heights.map(|_| None);
+
+    let mut to_search: Vec<V> = Vec::new();
+    to_search.push(start);
+    visited[start] = Some(0);
+
+    while let Some(current_v) = to_search.pop() {
+        let current_dist_from_start = visited[current_v].unwrap();
+
+        for neighbor in map.neighbors_inv(current_v) {
+            let cell = &mut visited[neighbor];
+            match cell {
+                Some(from_start) => {
+                    if *from_start > current_dist_from_start + 1 {
+                        *cell = Some(current_dist_from_start + 1);
+                        to_search.push(neighbor);
+                    }
+                }
+                None => {
+                    *cell = Some(current_dist_from_start + 1);
+                    to_search.push(neighbor);
+                }
+            }
+        }
+    }
+    visited
+}
+
+pub struct Day12;
+
+impl Solution for Day12 {
+    type Input = Map;
+    type Part1 = u32;
+    type Part2 = u32;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        input.parse()
+    }
+
+    fn part1(input: &Self::Input) -> Self::Part1 {
+        let visited = do_a_star_from(input.end, input);
+        visited[input.start].unwrap()
+    }
+
+    fn part2(input: &Self::Input) -> Self::Part2 {
+        let visited = do_a_star_from(input.end, input);
+
+        input
+            .heights
+            .iter()
+            .filter(|(_, height)| **height == 0)
+            .filter_map(|(v, _)| visited[v])
+            .min()
+            .unwrap()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn part1_example() {
+        let input = Day12::parse(EXAMPLE).unwrap();
+        assert_eq!(Day12::part1(&input), 31);
+    }
+
+    #[test]
+    fn part2_example() {
+        let input = Day12::parse(EXAMPLE).unwrap();
+        assert_eq!(Day12::part2(&input), 29);
+    }
+}
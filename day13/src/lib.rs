@@ -0,0 +1,122 @@
+use common::{ParseError, Scanner, Solution};
+
+pub const EXAMPLE: &str = include_str!("./example.txt");
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Item {
+    Value(u64),
+    List(Vec<Item>),
+}
+
+impl Ord for Item {
+    fn cmp(&self, other: &Self) -> std::cmp::Ordering {
+        match (self, other) {
+            (Item::Value(v), Item::Value(o_v)) => v.cmp(o_v),
+            (Item::List(l), Item::List(o_l)) => l.cmp(o_l),
+            (Item::Value(_), Item::List(o_l)) => (vec![self.clone()]).cmp(o_l),
+            (Item::List(l), Item::Value(_)) => l.cmp(&vec![other.clone()]),
+        }
+    }
+}
+
+impl PartialOrd for Item {
+    fn partial_cmp(&self, other: &Self) -> Option<std::cmp::Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Item {
+    fn scan(s: &mut Scanner<'_>) -> Result<Self, ParseError> {
+        if s.try_tag("[") {
+            let mut list = Vec::new();
+            if s.try_tag("]") {
+                return Ok(Self::List(list));
+            }
+            loop {
+                list.push(Self::scan(s)?);
+                if s.one_of(&[(",", false), ("]", true)])? {
+                    break;
+                }
+            }
+            Ok(Self::List(list))
+        } else if s.peek().is_some_and(|c| c.is_ascii_digit()) {
+            Ok(Self::Value(s.number()?))
+        } else {
+            Err(s.error("`[` or a number"))
+        }
+    }
+}
+
+pub struct Day13;
+
+impl Solution for Day13 {
+    type Input = Vec<(Item, Item)>;
+    type Part1 = usize;
+    type Part2 = usize;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        Scanner::parse(input.trim_end(), |s| {
+            let mut pairs = Vec::new();
+            loop {
+                let left = Item::scan(s)?;
+                s.newline()?;
+                let right = Item::scan(s)?;
+                pairs.push((left, right));
+                if s.is_empty() {
+                    break;
+                }
+                s.newline()?;
+                s.newline()?;
+            }
+            Ok(pairs)
+        })
+    }
+
+    fn part1(input: &Self::Input) -> Self::Part1 {
+        input
+            .iter()
+            .enumerate()
+            .filter(|(_, (left, right))| left < right)
+            .map(|(i, _)| i + 1)
+            .sum()
+    }
+
+    fn part2(input: &Self::Input) -> Self::Part2 {
+        let first_divider = Item::List(vec![Item::List(vec![Item::Value(2)])]);
+        let second_divider = Item::List(vec![Item::List(vec![Item::Value(6)])]);
+
+        let mut all = Vec::new();
+        for (left, right) in input {
+            all.push(left.clone());
+            all.push(right.clone());
+        }
+        all.push(first_divider.clone());
+        all.push(second_divider.clone());
+        all.sort();
+
+        let mut key = 1;
+        for (i, item) in all.iter().enumerate() {
+            if item == &first_divider || item == &second_divider {
+                key *= i + 1;
+            }
+        }
+        key
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn part1_example() {
+        let input = Day13::parse(EXAMPLE).unwrap();
+        assert_eq!(Day13::part1(&input), 13);
+    }
+
+    #[test]
+    fn part2_example() {
+        let input = Day13::parse(EXAMPLE).unwrap();
+        assert_eq!(Day13::part2(&input), 140);
+    }
+}
@@ -0,0 +1,161 @@
+use std::fmt::Display;
+
+use common::{parse_lines, ParseError, Solution};
+use geometry::{Grid, Vec2};
+
+pub const EXAMPLE: &str = include_str!("./example.txt");
+
+type V = Vec2<i32>;
+
+#[derive(Debug, Clone, PartialEq)]
+enum Cell {
+    Air,
+    Rock,
+    Sand,
+}
+
+impl Display for Cell {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let c = match self {
+            Cell::Air => ' ',
+            Cell::Rock => '#',
+            Cell::Sand => 'o',
+        };
+        write!(f, "{c}")
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+struct Map {
+    map: Grid<Cell>,
+    start: V,
+}
+
+impl Display for Map {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.map)
+    }
+}
+
+fn build_map(paths: &[Vec<V>], floor: bool) -> Map {
+    let mut min_x = 99999999;
+    let mut max_x = -99999999;
+    let mut min_y = 99999999;
+    let mut max_y = -99999999;
+
+    let mut lines = paths.to_vec();
+    for v in paths.iter().flatten() {
+        min_x = min_x.min(v.x);
+        max_x = max_x.max(v.x);
+        min_y = min_y.min(v.y);
+        max_y = max_y.max(v.y);
+    }
+    min_x = min_x.min(0);
+    max_x = max_x.max(1000);
+    min_y = min_y.min(0);
+    max_y = max_y.max(0);
+
+    if floor {
+        lines.push(vec![V::new(0, max_y + 2), V::new(999, max_y + 2)]);
+    }
+
+    max_x += 1;
+    max_y += 3;
+    min_x -= 1;
+    min_y -= 1;
+
+    let mut map = Map {
+        map: Grid::new(
+            (max_x - min_x) as usize,
+            (max_y - min_y) as usize,
+            Cell::Air,
+        ),
+        start: V::new(500 - min_x, -min_y),
+    };
+
+    for line in lines {
+        for [from, to] in line.array_windows() {
+            for x in from.x.min(to.x)..=to.x.max(from.x) {
+                for y in from.y.min(to.y)..=to.y.max(from.y) {
+                    map.map[V::new(x - min_x, y - min_y)] = Cell::Rock;
+                }
+            }
+        }
+    }
+    map
+}
+
+fn pour(map: &mut Map) -> usize {
+    let mut amount = 0;
+    'outer: loop {
+        let mut sand = map.start;
+
+        loop {
+            if map.map.get(sand + V::new(0, 1)) == Some(&Cell::Air) {
+                sand += V::new(0, 1);
+            } else if map.map.get(sand + V::new(-1, 1)) == Some(&Cell::Air) {
+                sand += V::new(-1, 1);
+            } else if map.map.get(sand + V::new(1, 1)) == Some(&Cell::Air) {
+                sand += V::new(1, 1);
+            } else if map.map.get(sand + V::new(0, 1)).is_none() {
+                break 'outer;
+            } else {
+                match map.map.get_mut(sand) {
+                    Some(c) => {
+                        amount += 1;
+                        *c = Cell::Sand;
+                        if sand == map.start {
+                            break 'outer;
+                        }
+                        break;
+                    }
+                    None => break 'outer,
+                }
+            }
+        }
+    }
+    amount
+}
+
+pub struct Day14;
+
+impl Solution for Day14 {
+    type Input = Vec<Vec<V>>;
+    type Part1 = usize;
+    type Part2 = usize;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        parse_lines(input, |s| {
+            let mut path = vec![V::scan(s)?];
+            while s.try_tag(" -> ") {
+                path.push(V::scan(s)?);
+            }
+            Ok(path)
+        })
+    }
+
+    fn part1(input: &Self::Input) -> Self::Part1 {
+        pour(&mut build_map(input, false))
+    }
+
+    fn part2(input: &Self::Input) -> Self::Part2 {
+        pour(&mut build_map(input, true))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn part1_example() {
+        let input = Day14::parse(EXAMPLE).unwrap();
+        assert_eq!(Day14::part1(&input), 24);
+    }
+
+    #[test]
+    fn part2_example() {
+        let input = Day14::parse(EXAMPLE).unwrap();
+        assert_eq!(Day14::part2(&input), 93);
+    }
+}
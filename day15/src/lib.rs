@@ -0,0 +1,131 @@
+use std::collections::HashSet;
+
+use common::{parse_lines, ParseError, Solution};
+use geometry::{IntervalSet, Vec2};
+
+pub const EXAMPLE: &str = include_str!("./example.txt");
+
+type V = Vec2<i32>;
+
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct Sensor {
+    pos: V,
+    dist: i32,
+    beacon: V,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Scan {
+    sensors: Vec<Sensor>,
+    know_beacons: HashSet<V>,
+}
+
+impl Scan {
+    /// The part of row `y` that is closer to a sensor than its beacon
+    fn row_coverage(&self, y: i32) -> IntervalSet<i32> {
+        self.sensors
+            .iter()
+            .map(|s| {
+                let reach = s.dist - s.pos.y.abs_diff(y) as i32;
+                (s.pos.x - reach)..=(s.pos.x + reach)
+            })
+            .collect()
+    }
+
+    /// How many positions in row `y` can't have a beacon
+    pub fn no_beacon_in_row(&self, y: i32) -> usize {
+        let beacons: IntervalSet<i32> = self
+            .know_beacons
+            .iter()
+            .filter(|b| b.y == y)
+            .map(|b| b.x..=b.x)
+            .collect();
+
+        self.row_coverage(y).difference(&beacons).total_length() as usize
+    }
+
+    /// The tuning frequency of the only position from 0 to `max` in both directions that no
+    /// sensor covers
+    pub fn tuning_frequency(&self, max: i32) -> u64 {
+        let area = IntervalSet::from(0..=max);
+
+        for y in 0..=max {
+            let free = area.difference(&self.row_coverage(y));
+            if let Some(x) = free.ranges().next() {
+                return *x.start() as u64 * 4000000 + y as u64;
+            };
+        }
+        panic!("No place left for the distress beacon")
+    }
+}
+
+/// The row part 1 looks at
+const ROW: i32 = 2000000;
+/// How far the distress beacon can be in both directions
+const MAX: i32 = 4000000;
+
+pub struct Day15;
+
+impl Solution for Day15 {
+    type Input = Scan;
+    type Part1 = usize;
+    type Part2 = u64;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        let readings = parse_lines(input, |s| {
+            s.tag("Sensor at ")?;
+            let sensor_pos = V::scan(s)?;
+            s.tag(": closest beacon is at ")?;
+            let beacon_pos = V::scan(s)?;
+            Ok((sensor_pos, beacon_pos))
+        })?;
+
+        let mut sensors = Vec::new();
+
+        let mut know_beacons = HashSet::new();
+
+        for (sensor_pos, beacon_pos) in readings {
+            know_beacons.insert(beacon_pos);
+
+            let dist = sensor_pos.manhattan(&beacon_pos);
+
+            let sensor = Sensor {
+                pos: sensor_pos,
+                dist,
+                beacon: beacon_pos,
+            };
+            sensors.push(sensor);
+        }
+
+        Ok(Scan {
+            sensors,
+            know_beacons,
+        })
+    }
+
+    fn part1(input: &Self::Input) -> Self::Part1 {
+        input.no_beacon_in_row(ROW)
+    }
+
+    fn part2(input: &Self::Input) -> Self::Part2 {
+        input.tuning_frequency(MAX)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn part1_example() {
+        // the example only checks row 10 and searches a 20 by 20 area
+        let input = Day15::parse(EXAMPLE).unwrap();
+        assert_eq!(input.no_beacon_in_row(10), 26);
+    }
+
+    #[test]
+    fn part2_example() {
+        let input = Day15::parse(EXAMPLE).unwrap();
+        assert_eq!(input.tuning_frequency(20), 56000011);
+    }
+}
@@ -0,0 +1,400 @@
+use common::{parse_lines, ParseError, Scanner, Solution};
+use std::collections::{BTreeSet, HashMap, HashSet, VecDeque};
+
+pub const EXAMPLE: &str = include_str!("./example.txt");
+
+#[derive(Copy, Clone, Hash, PartialEq, Eq, PartialOrd, Ord)]
+pub struct RoomID(u16);
+
+impl std::fmt::Debug for RoomID {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let b = self.0 as u8;
+        let a = (self.0 >> 8) as u8;
+        write!(f, "{}{}", a as char, b as char)
+    }
+}
+
+fn name_to_room_id(name: &str) -> RoomID {
+    let id = match name.as_bytes() {
+        [a, b] => (*a as u16) << 8 | (*b as u16),
+        _ => panic!(
+            "name needs to be 2 bytes long, not {} bytes long",
+            name.len()
+        ),
+    };
+    RoomID(id)
+}
+
+fn scan_room_id(s: &mut Scanner<'_>) -> std::result::Result<RoomID, ParseError> {
+    let start = s.clone();
+    let name = s.take_while(|c| c.is_ascii_uppercase());
+    if name.len() != 2 {
+        return Err(start.error("a valve name of two capital letters"));
+    }
+    Ok(name_to_room_id(name))
+}
+
+#[derive(Debug, Clone)]
+pub struct Room {
+    flow_rate: u64,
+    leads_to: Vec<RoomID>,
+}
+
+#[derive(Debug, Clone, Hash, PartialEq, Eq)]
+struct Current {
+    room_me: RoomID,
+    room_el: RoomID,
+    move_time_left_me: u64,
+    move_time_left_el: u64,
+
+    time_left: u64,
+    total_flow_rate: u64,
+    claimd_rooms: BTreeSet<RoomID>,
+}
+
+impl Current {
+    fn update(&mut self, rooms: &HashMap<RoomID, Room>) -> u64 {
+        let used_time = self.move_time_left_me.min(self.move_time_left_el);
+
+        let released = self.total_flow_rate * used_time;
+
+        self.move_time_left_me -= used_time;
+        self.move_time_left_el -= used_time;
+        self.time_left -= used_time;
+
+        if self.move_time_left_me == 0 {
+            self.total_flow_rate += rooms.get(&self.room_me).unwrap().flow_rate;
+        }
+        if self.move_time_left_el == 0 {
+            self.total_flow_rate += rooms.get(&self.room_el).unwrap().flow_rate;
+        }
+        released
+    }
+
+    fn update_non_null(&mut self, rooms: &HashMap<RoomID, Room>) -> u64 {
+        let used_time = self.move_time_left_me.max(self.move_time_left_el);
+        if used_time > self.time_left {
+            return 0;
+        }
+
+        let released = self.total_flow_rate * used_time;
+        self.time_left -= used_time;
+
+        if self.move_time_left_me != 0 {
+            self.move_time_left_me -= used_time;
+            self.total_flow_rate += rooms.get(&self.room_me).unwrap().flow_rate;
+        }
+        if self.move_time_left_el != 0 {
+            self.move_time_left_el -= used_time;
+            self.total_flow_rate += rooms.get(&self.room_el).unwrap().flow_rate;
+        }
+        released
+    }
+}
+
+type Result = u64;
+
+fn find_best(
+    rooms: &HashMap<RoomID, Room>,
+    best_paths: &HashMap<RoomID, HashMap<RoomID, u64>>,
+    current: Current,
+    saved: &mut HashMap<Current, Result>,
+) -> Result {
+    debug_assert!(current.time_left > 0);
+
+    if let Some(total) = saved.get(&current) {
+        return *total;
+    }
+
+    let filter = |(&to_id, &length)| {
+        let length = length + 1;
+        if current.claimd_rooms.contains(&to_id) {
+            return None;
+        }
+        if current.time_left < length {
+            return None;
+        }
+        Some(Some((to_id, length)))
+    };
+
+    let me_options = if current.move_time_left_me == 0 {
+        best_paths
+            .get(&current.room_me)
+            .unwrap()
+            .iter()
+            .filter_map(filter)
+            .collect()
+    } else {
+        vec![None]
+    };
+    let el_options = if current.move_time_left_el == 0 {
+        best_paths
+            .get(&current.room_el)
+            .unwrap()
+            .iter()
+            .filter_map(filter)
+            .collect()
+    } else {
+        vec![None]
+    };
+
+    let mut best_release = 0;
+    for me_data in &me_options {
+        for el_data in &el_options {
+            let mut new_current = current.clone();
+
+            if let &Some((me_to_id, me_length)) = me_data {
+                if let Some((el_to_id, _)) = el_data {
+                    if el_to_id == &me_to_id {
+                        continue;
+                    }
+                }
+                new_current.move_time_left_me = me_length;
+                new_current.claimd_rooms.insert(me_to_id);
+                new_current.room_me = me_to_id;
+            }
+            if let &Some((el_to_id, el_length)) = el_data {
+                new_current.move_time_left_el = el_length;
+                new_current.claimd_rooms.insert(el_to_id);
+                new_current.room_el = el_to_id;
+            }
+
+            let released = new_current.update(rooms);
+
+            let released = released + find_best(rooms, best_paths, new_current, saved);
+
+            best_release = best_release.max(released);
+        }
+    }
+    let mut new_current = current.clone();
+
+    assert!(!(new_current.move_time_left_el != 0 && new_current.move_time_left_me != 0));
+
+    let mut released = 0;
+    if new_current.move_time_left_el != 0 || new_current.move_time_left_me != 0 {
+        released += new_current.update_non_null(rooms);
+    }
+    released += new_current.total_flow_rate * new_current.time_left;
+    best_release = best_release.max(released);
+    saved.insert(current, best_release);
+    best_release
+}
+
+fn find_best_alone(
+    rooms: &HashMap<RoomID, Room>,
+    best_paths: &HashMap<RoomID, HashMap<RoomID, u64>>,
+    room: RoomID,
+    time_left: u64,
+    claimd_rooms: &mut BTreeSet<RoomID>,
+) -> Result {
+    let mut best_release = 0;
+    for (&to_id, &length) in best_paths.get(&room).unwrap() {
+        let length = length + 1;
+        if claimd_rooms.contains(&to_id) || time_left < length {
+            continue;
+        }
+        let new_time_left = time_left - length;
+        let released = rooms.get(&to_id).unwrap().flow_rate * new_time_left;
+
+        claimd_rooms.insert(to_id);
+        let released =
+            released + find_best_alone(rooms, best_paths, to_id, new_time_left, claimd_rooms);
+        claimd_rooms.remove(&to_id);
+
+        best_release = best_release.max(released);
+    }
+    best_release
+}
+
+/// The number of steps from `from` to `to`, `None` if no tunnels lead there
+fn find_shortest_path(rooms: &HashMap<RoomID, Room>, from: RoomID, to: RoomID) -> Option<u64> {
+    if from == to {
+        return Some(0);
+    }
+    let mut visited = HashSet::new();
+    visited.insert(from);
+    let mut to_search = VecDeque::with_capacity(1);
+    to_search.push_back((from, 0));
+
+    while !to_search.is_empty() {
+        let (current_id, current_l) = to_search.pop_front().unwrap();
+        let current = rooms.get(&current_id).unwrap();
+
+        for leads_to in &current.leads_to {
+            if leads_to == &to {
+                return Some(current_l + 1);
+            }
+            if !visited.contains(leads_to) {
+                visited.insert(*leads_to);
+                to_search.push_back((*leads_to, current_l + 1))
+            }
+        }
+    }
+
+    None
+}
+
+#[derive(Debug, Clone)]
+pub struct Volcano {
+    rooms: HashMap<RoomID, Room>,
+    best_paths: HashMap<RoomID, HashMap<RoomID, u64>>,
+}
+
+pub struct Day16;
+
+impl Solution for Day16 {
+    type Input = Volcano;
+    type Part1 = u64;
+    type Part2 = u64;
+
+    fn parse(input: &str) -> std::result::Result<Self::Input, ParseError> {
+        // where every tunnel end is, to point at the ones that lead nowhere
+        let mut tunnels = Vec::new();
+        let mut line = 0;
+
+        // where every valve is named
+        let mut names = Vec::new();
+
+        let valves = parse_lines(input, |s| {
+            s.tag("Valve ")?;
+            names.push((line, s.clone()));
+            let name = scan_room_id(s)?;
+            s.tag(" has flow rate=")?;
+            let flow_rate = s.number()?;
+            if !s.try_tag("; tunnels lead to valves ") {
+                s.tag("; tunnel leads to valve ")?;
+            }
+
+            let mut leads_to = Vec::new();
+            loop {
+                let at = s.clone();
+                let to = scan_room_id(s)?;
+                tunnels.push((line, at, to));
+                leads_to.push(to);
+                if !s.try_tag(", ") {
+                    break;
+                }
+            }
+            line += 1;
+
+            Ok((
+                name,
+                Room {
+                    flow_rate,
+                    leads_to,
+                },
+            ))
+        })?;
+        let rooms: HashMap<RoomID, Room> = valves.into_iter().collect();
+
+        // every tunnel has to end up at a valve that is in the scan
+        for (line, at, to) in tunnels {
+            if !rooms.contains_key(&to) {
+                return Err(at.error("a valve from the scan").offset_line(line));
+            }
+        }
+
+        let start = name_to_room_id("AA");
+        if !rooms.contains_key(&start) {
+            return Err(ParseError::new("a valve named `AA`", "end of input")
+                .offset_line(input.lines().count()));
+        }
+        // every valve worth opening has to be reachable from the start
+        for (line, at) in names {
+            let name = scan_room_id(&mut at.clone())?;
+            if rooms[&name].flow_rate > 0 && find_shortest_path(&rooms, start, name).is_none() {
+                return Err(at
+                    .error("a valve that can be reached from `AA`")
+                    .offset_line(line));
+            }
+        }
+
+        let mut best_paths = HashMap::new();
+
+        for from in rooms.keys() {
+            let mut best_paths_from = HashMap::new();
+            for to in rooms.keys() {
+                if rooms.get(to).unwrap().flow_rate == 0 {
+                    continue;
+                }
+                // some valves may be out of reach from valves that can't be reached anyway
+                if let Some(l) = find_shortest_path(&rooms, *from, *to) {
+                    best_paths_from.insert(*to, l);
+                }
+            }
+            best_paths.insert(*from, best_paths_from);
+        }
+
+        Ok(Volcano { rooms, best_paths })
+    }
+
+    fn part1(input: &Self::Input) -> Self::Part1 {
+        let start = name_to_room_id("AA");
+
+        find_best_alone(
+            &input.rooms,
+            &input.best_paths,
+            start,
+            30,
+            &mut BTreeSet::new(),
+        )
+    }
+
+    fn part2(input: &Self::Input) -> Self::Part2 {
+        let start = name_to_room_id("AA");
+
+        let current = Current {
+            room_me: start,
+            room_el: start,
+            move_time_left_me: 0,
+            move_time_left_el: 0,
+            time_left: 26,
+            total_flow_rate: 0,
+            claimd_rooms: BTreeSet::new(),
+        };
+
+        let mut saved = HashMap::new();
+
+        find_best(&input.rooms, &input.best_paths, current, &mut saved)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn part1_example() {
+        let input = Day16::parse(EXAMPLE).unwrap();
+        assert_eq!(Day16::part1(&input), 1651);
+    }
+
+    #[test]
+    fn part2_example() {
+        let input = Day16::parse(EXAMPLE).unwrap();
+        assert_eq!(Day16::part2(&input), 1707);
+    }
+
+    #[test]
+    fn unknown_valve() {
+        let input = EXAMPLE.replace("valve II", "valve QQ");
+        let error = Day16::parse(&input).unwrap_err();
+        assert_eq!((error.line, error.column), (10, 50));
+        assert_eq!(error.found, "`QQ`");
+    }
+
+    #[test]
+    fn unreachable_valves() {
+        let error = Day16::parse("").unwrap_err();
+        assert_eq!(error.expected, "a valve named `AA`");
+
+        let input = "\
+Valve AA has flow rate=0; tunnel leads to valve BB
+Valve BB has flow rate=3; tunnel leads to valve AA
+Valve CC has flow rate=5; tunnel leads to valve BB
+";
+        let error = Day16::parse(input).unwrap_err();
+        assert_eq!((error.line, error.column), (3, 7));
+        assert_eq!(error.expected, "a valve that can be reached from `AA`");
+    }
+}
@@ -0,0 +1,251 @@
+use std::{collections::HashMap, fmt::Display, str::FromStr};
+
+use canvas::{Display2D, YAxis};
+use common::{ParseError, Scanner, Solution};
+use geometry::{Grid, Vec2};
+
+pub const EXAMPLE: &str = include_str!("./example.txt");
+
+type V = Vec2<i64>;
+
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum Jet {
+    Left,
+    Right,
+}
+
+impl Jet {
+    fn to_v(&self) -> V {
+        match self {
+            Jet::Left => V::new(-1, 0),
+            Jet::Right => V::new(1, 0),
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+struct Shape(Vec<V>);
+
+impl Shape {
+    fn shift(&mut self, v: &V) {
+        for p in self.0.iter_mut() {
+            *p += v;
+        }
+    }
+
+    fn with_shift<'a>(&'a self, v: &'a V) -> impl Iterator<Item = V> + 'a {
+        self.0.iter().map(move |p| p + v)
+    }
+}
+
+impl FromStr for Shape {
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let rows = Grid::parse_with(s, |c| match c {
+            '#' => Ok(true),
+            '.' => Ok(false),
+            _ => Err(ParseError::new("`#` or `.`", format_args!("`{c}`"))),
+        })?;
+        let height = rows.height() as i64;
+
+        let points = rows
+            .iter()
+            .filter(|(_, rock)| **rock)
+            .map(|(p, _)| V::new(p.x as i64, height - p.y as i64 - 1))
+            .collect();
+
+        Ok(Self(points))
+    }
+}
+
+fn shapes() -> Vec<Shape> {
+    vec![
+        Shape::from_str("####").unwrap(),
+        Shape::from_str(
+            ".#.
+###
+.#.",
+        )
+        .unwrap(),
+        Shape::from_str(
+            r#"..#
+..#
+###"#,
+        )
+        .unwrap(),
+        Shape::from_str(
+            r#"#
+#
+#
+#"#,
+        )
+        .unwrap(),
+        Shape::from_str(
+            r#"##
+##"#,
+        )
+        .unwrap(),
+    ]
+}
+
+#[derive(Debug, Clone, Default)]
+pub struct Chamber {
+    shape_index: usize,
+    jet_index: usize,
+    height: i64,
+    column_heights: [i64; 7],
+    fallen: HashMap<V, usize>,
+}
+
+impl Chamber {
+    fn fall(&mut self, shapes: &[Shape], jets: &[Jet]) {
+        let mut shape = shapes[self.shape_index].clone();
+        shape.shift(&V::new(2, self.height + 3));
+
+        let fallen = &self.fallen;
+        let mut try_shift = |v: &V| -> bool {
+            let mut fits = true;
+            for p in shape.with_shift(v) {
+                if p.x < 0 || p.x >= 7 || p.y < 0 || fallen.contains_key(&p) {
+                    fits = false;
+                    break;
+                }
+            }
+            if fits {
+                shape.shift(v);
+            }
+            fits
+        };
+
+        loop {
+            let jet = jets[self.jet_index].clone();
+            self.jet_index = (self.jet_index + 1) % jets.len();
+
+            try_shift(&jet.to_v());
+
+            if !try_shift(&V::new(0, -1)) {
+                for p in shape.0 {
+                    self.height = self.height.max(p.y + 1);
+                    self.column_heights[p.x as usize] =
+                        self.column_heights[p.x as usize].max(p.y + 1);
+                    self.fallen.insert(p, self.shape_index);
+                }
+                break;
+            }
+        }
+        self.shape_index = (self.shape_index + 1) % shapes.len();
+    }
+
+    /// Everything needed to know how the next shapes will fall
+    fn state(&self) -> (usize, usize, [i64; 7]) {
+        (
+            self.shape_index,
+            self.jet_index,
+            self.column_heights.map(|h| self.height - h),
+        )
+    }
+}
+
+impl Display for Chamber {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        // the walls and the floor take up one extra cell on every side
+        let mut d = Display2D::with_background(9, self.height as usize + 1, '.').y_axis(YAxis::Up);
+        for y in 1..=self.height as i32 {
+            d.draw(Vec2::new(0, y), '|');
+            d.draw(Vec2::new(8, y), '|');
+        }
+        for x in 1..8 {
+            d.draw(Vec2::new(x, 0), '-');
+        }
+        d.draw(Vec2::new(0, 0), '+');
+        d.draw(Vec2::new(8, 0), '+');
+
+        for (p, i) in &self.fallen {
+            let c = char::from_digit(*i as u32, 10).unwrap();
+            d.draw(Vec2::new(p.x as i32 + 1, p.y as i32 + 1), c);
+        }
+        write!(f, "{}", d)
+    }
+}
+
+fn tower_height(jets: &[Jet], max_shapes: u64) -> i64 {
+    let shapes = shapes();
+
+    let mut chamber = Chamber::default();
+    let mut seen = HashMap::new();
+
+    let mut total_shapes = 0;
+    let mut skipped_height = 0;
+    while total_shapes < max_shapes {
+        chamber.fall(&shapes, jets);
+        total_shapes += 1;
+
+        if skipped_height != 0 {
+            continue;
+        }
+        if let Some((old_shapes, old_height)) =
+            seen.insert(chamber.state(), (total_shapes, chamber.height))
+        {
+            let cycle_shapes = total_shapes - old_shapes;
+            let repeats = (max_shapes - total_shapes) / cycle_shapes;
+
+            total_shapes += repeats * cycle_shapes;
+            skipped_height = (chamber.height - old_height) * repeats as i64;
+        }
+    }
+
+    chamber.height + skipped_height
+}
+
+pub struct Day17;
+
+impl Solution for Day17 {
+    type Input = Vec<Jet>;
+    type Part1 = i64;
+    type Part2 = i64;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        Scanner::parse(input.trim_end(), |s| {
+            // at least one jet, the rocks are pushed around forever
+            let mut jets = Vec::new();
+            loop {
+                jets.push(s.one_of(&[("<", Jet::Left), (">", Jet::Right)])?);
+                if s.is_empty() {
+                    return Ok(jets);
+                }
+            }
+        })
+    }
+
+    fn part1(input: &Self::Input) -> Self::Part1 {
+        tower_height(input, 2022)
+    }
+
+    fn part2(input: &Self::Input) -> Self::Part2 {
+        tower_height(input, 1000000000000)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn part1_example() {
+        let input = Day17::parse(EXAMPLE).unwrap();
+        assert_eq!(Day17::part1(&input), 3068);
+    }
+
+    #[test]
+    fn part2_example() {
+        let input = Day17::parse(EXAMPLE).unwrap();
+        assert_eq!(Day17::part2(&input), 1514285714288);
+    }
+
+    #[test]
+    fn no_jets() {
+        let error = Day17::parse("\n").unwrap_err();
+        assert_eq!(error.found, "end of line");
+    }
+}
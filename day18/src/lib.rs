@@ -0,0 +1,178 @@
+use std::fmt::Display;
+
+use common::{parse_lines, ParseError, Solution};
+use geometry::Vec3;
+
+pub const EXAMPLE: &str = include_str!("./example.txt");
+
+type V = Vec3<i64>;
+
+#[derive(Debug, Clone, PartialEq)]
+enum Cell {
+    Air,
+    Lava,
+    Steam,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+struct Map {
+    map: Vec<Vec<Vec<Cell>>>,
+}
+
+impl Display for Map {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        for plane in &self.map {
+            for line in plane {
+                let s = line
+                    .iter()
+                    .map(|c| match c {
+                        Cell::Air => ' ',
+                        Cell::Lava => '#',
+                        Cell::Steam => 'o',
+                    })
+                    .collect::<String>();
+                writeln!(f, "{s}")?;
+            }
+            writeln!(f)?;
+        }
+        Ok(())
+    }
+}
+
+impl Map {
+    fn new(width: usize, height: usize, depth: usize) -> Self {
+        let mut map = Vec::new();
+        for _ in 0..height {
+            let mut plane = Vec::new();
+            for _ in 0..depth {
+                plane.push(vec![Cell::Air; width]);
+            }
+            map.push(plane);
+        }
+        Self { map }
+    }
+
+    fn get<'a>(&'a self, v: &V) -> Option<&'a Cell> {
+        if v.y < 0 || v.x < 0 || v.z < 0 {
+            return None;
+        }
+        self.map
+            .get(v.y as usize)
+            .and_then(|l| l.get(v.x as usize))
+            .and_then(|l| l.get(v.z as usize))
+    }
+
+    fn get_mut<'a>(&'a mut self, v: &V) -> Option<&'a mut Cell> {
+        if v.y < 0 || v.x < 0 || v.z < 0 {
+            return None;
+        }
+        self.map
+            .get_mut(v.y as usize)
+            .and_then(|l| l.get_mut(v.x as usize))
+            .and_then(|l| l.get_mut(v.z as usize))
+    }
+
+    fn flood(&mut self, start: V) {
+        if matches!(self.get(&start), None | Some(&Cell::Steam | &Cell::Lava)) {
+            return;
+        }
+        *self.get_mut(&start).unwrap() = Cell::Steam;
+
+        for side in start.neighbours() {
+            self.flood(side);
+        }
+    }
+}
+
+fn build_map(cubes: &[V]) -> (Map, usize) {
+    let size = cubes
+        .iter()
+        .map(|p| p.x.max(p.y).max(p.z))
+        .max()
+        .unwrap_or(0) as usize
+        + 3;
+
+    let mut map = Map::new(size, size, size);
+
+    for p in cubes {
+        *(map.get_mut(&(p + V::new(1, 1, 1))).unwrap()) = Cell::Lava;
+    }
+
+    (map, size)
+}
+
+fn count_sides(map: &Map, size: usize, touches: Cell) -> usize {
+    let mut amount = 0;
+
+    for x in 0..size {
+        for y in 0..size {
+            for z in 0..size {
+                let p = V::new(x as i64, y as i64, z as i64);
+                if map.get(&p) != Some(&Cell::Lava) {
+                    continue;
+                }
+                for side in p.neighbours() {
+                    if map.get(&side) == Some(&touches) {
+                        amount += 1;
+                    }
+                }
+            }
+        }
+    }
+
+    amount
+}
+
+pub struct Day18;
+
+impl Solution for Day18 {
+    type Input = Vec<V>;
+    type Part1 = usize;
+    type Part2 = usize;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        parse_lines(input, |s| {
+            let at = s.clone();
+            let cube = V::scan(s)?;
+            if cube.x < 0 || cube.y < 0 || cube.z < 0 {
+                return Err(at.error("a cube without negative coordinates"));
+            }
+            Ok(cube)
+        })
+    }
+
+    fn part1(input: &Self::Input) -> Self::Part1 {
+        let (map, size) = build_map(input);
+        count_sides(&map, size, Cell::Air)
+    }
+
+    fn part2(input: &Self::Input) -> Self::Part2 {
+        let (mut map, size) = build_map(input);
+        map.flood(V::new(0, 0, 0));
+        count_sides(&map, size, Cell::Steam)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn part1_example() {
+        let input = Day18::parse(EXAMPLE).unwrap();
+        assert_eq!(Day18::part1(&input), 64);
+    }
+
+    #[test]
+    fn part2_example() {
+        let input = Day18::parse(EXAMPLE).unwrap();
+        assert_eq!(Day18::part2(&input), 58);
+    }
+
+    #[test]
+    fn negative_coordinates() {
+        let error = Day18::parse("1,2,3\n1,-2,3").unwrap_err();
+        assert_eq!((error.line, error.column), (2, 1));
+        assert_eq!(error.found, "`1,-2,3`");
+    }
+}
@@ -1,7 +1,9 @@
-#![feature(int_roundings)]
-
 use std::{collections::HashMap, str::FromStr};
 
+use common::{parse_lines, ParseError, Scanner, Solution};
+
+pub const EXAMPLE: &str = include_str!("./example.txt");
+
 #[derive(Debug, Clone)]
 struct Recipie {
     needed_ore: i16,
@@ -21,7 +23,8 @@ impl Recipie {
 }
 
 #[derive(Debug, Clone)]
-struct Blueprint {
+pub struct Blueprint {
+    id: i16,
     ore_robot: Recipie,
     clay_robot: Recipie,
     obsidian_robot: Recipie,
@@ -29,45 +32,46 @@ struct Blueprint {
     max_robot: Recipie,
 }
 
-impl FromStr for Blueprint {
-    type Err = ();
-
-    fn from_str(s: &str) -> Result<Self, Self::Err> {
-        let s = s.strip_prefix("Blueprint ").ok_or(())?;
-        let (_, rest) = s.split_once(": Each ore robot costs ").ok_or(())?;
-        let (ore_for_ore, rest) = rest.split_once(" ore. Each clay robot costs ").ok_or(())?;
-        let (ore_for_clay, rest) = rest
-            .split_once(" ore. Each obsidian robot costs ")
-            .ok_or(())?;
-        let (ore_for_obsidian, rest) = rest.split_once(" ore and ").ok_or(())?;
-        let (clay_for_obsidian, rest) = rest
-            .split_once(" clay. Each geode robot costs ")
-            .ok_or(())?;
-        let (ore_for_geode, rest) = rest.split_once(" ore and ").ok_or(())?;
-        let obsidian_for_geode = rest.strip_suffix(" obsidian.").ok_or(())?;
+impl Blueprint {
+    pub fn scan(s: &mut Scanner) -> Result<Self, ParseError> {
+        s.tag("Blueprint ")?;
+        let id = s.number()?;
+        s.tag(": Each ore robot costs ")?;
+        let ore_for_ore = s.number()?;
+        s.tag(" ore. Each clay robot costs ")?;
+        let ore_for_clay = s.number()?;
+        s.tag(" ore. Each obsidian robot costs ")?;
+        let ore_for_obsidian = s.number()?;
+        s.tag(" ore and ")?;
+        let clay_for_obsidian = s.number()?;
+        s.tag(" clay. Each geode robot costs ")?;
+        let ore_for_geode = s.number()?;
+        s.tag(" ore and ")?;
+        let obsidian_for_geode = s.number()?;
+        s.tag(" obsidian.")?;
 
         let ore_recipie = Recipie {
-            needed_ore: ore_for_ore.parse().map_err(|_| ())?,
+            needed_ore: ore_for_ore,
             needed_clay: 0,
             needed_obsidian: 0,
         };
 
         let clay_recipie = Recipie {
-            needed_ore: ore_for_clay.parse().map_err(|_| ())?,
+            needed_ore: ore_for_clay,
             needed_clay: 0,
             needed_obsidian: 0,
         };
 
         let obsidian_recipie = Recipie {
-            needed_ore: ore_for_obsidian.parse().map_err(|_| ())?,
-            needed_clay: clay_for_obsidian.parse().map_err(|_| ())?,
+            needed_ore: ore_for_obsidian,
+            needed_clay: clay_for_obsidian,
             needed_obsidian: 0,
         };
 
         let geode_recipie = Recipie {
-            needed_ore: ore_for_geode.parse().map_err(|_| ())?,
+            needed_ore: ore_for_geode,
             needed_clay: 0,
-            needed_obsidian: obsidian_for_geode.parse().map_err(|_| ())?,
+            needed_obsidian: obsidian_for_geode,
         };
         let max_robot = ore_recipie
             .max(&clay_recipie)
@@ -75,6 +79,7 @@ impl FromStr for Blueprint {
             .max(&geode_recipie);
 
         Ok(Blueprint {
+            id,
             ore_robot: ore_recipie,
             clay_robot: clay_recipie,
             obsidian_robot: obsidian_recipie,
@@ -84,6 +89,14 @@ impl FromStr for Blueprint {
     }
 }
 
+impl FromStr for Blueprint {
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Scanner::parse(s, Self::scan)
+    }
+}
+
 #[derive(Debug, Clone, Hash, PartialEq, Eq, PartialOrd, Ord, Default)]
 struct Resource {
     amount: i16,
@@ -119,18 +132,40 @@ impl Current {
         self.clay_resources.amount -= recipie.needed_clay;
         self.obsidian_resources.amount -= recipie.needed_obsidian;
     }
+
+    /// The geodes we would end with if a geode robot could be built every remaining minute.
+    fn upper_bound(&self) -> Ans {
+        let t = self.time_left;
+        self.geode_resources.amount + self.geode_resources.speed * t + t * (t - 1) / 2
+    }
 }
 
 type Ans = i16;
 
-fn run(current: Current, blueprint: &Blueprint, mem: &mut HashMap<Current, Ans>) -> Ans {
+struct Search<'a> {
+    blueprint: &'a Blueprint,
+    mem: HashMap<Current, Ans>,
+    best: Ans,
+}
+
+/// Rounds up, only for a positive amount and speed
+fn div_ceil(amount: i16, speed: i16) -> i16 {
+    (amount + speed - 1) / speed
+}
+
+fn simulate(current: Current, search: &mut Search) -> Ans {
     if current.time_left == 0 {
         return current.geode_resources.amount;
     }
     assert!(current.time_left > 0);
-    if let Some(a) = mem.get(&current) {
+    if let Some(a) = search.mem.get(&current) {
         return *a;
     }
+    // a branch that can't beat the best one found so far doesn't need to be explored
+    if current.upper_bound() <= search.best {
+        return current.geode_resources.amount;
+    }
+    let blueprint = search.blueprint;
 
     let try_create = |recipie: &Recipie| {
         let mut max_time_needed = 1;
@@ -140,7 +175,7 @@ fn run(current: Current, blueprint: &Blueprint, mem: &mut HashMap<Current, Ans>)
             // alreade have enough ore
         } else if current.ore_resources.speed != 0 {
             max_time_needed =
-                max_time_needed.max(needed_ore.div_ceil(current.ore_resources.speed) + 1);
+                max_time_needed.max(div_ceil(needed_ore, current.ore_resources.speed) + 1);
         } else {
             return None;
         }
@@ -150,7 +185,7 @@ fn run(current: Current, blueprint: &Blueprint, mem: &mut HashMap<Current, Ans>)
             // alreade have enough clay
         } else if current.clay_resources.speed != 0 {
             max_time_needed =
-                max_time_needed.max(needed_clay.div_ceil(current.clay_resources.speed) + 1);
+                max_time_needed.max(div_ceil(needed_clay, current.clay_resources.speed) + 1);
         } else {
             return None;
         }
@@ -159,8 +194,8 @@ fn run(current: Current, blueprint: &Blueprint, mem: &mut HashMap<Current, Ans>)
         if needed_obsidian <= 0 {
             // alreade have enough obsidian
         } else if current.obsidian_resources.speed != 0 {
-            max_time_needed =
-                max_time_needed.max(needed_obsidian.div_ceil(current.obsidian_resources.speed) + 1);
+            max_time_needed = max_time_needed
+                .max(div_ceil(needed_obsidian, current.obsidian_resources.speed) + 1);
         } else {
             return None;
         }
@@ -180,41 +215,34 @@ fn run(current: Current, blueprint: &Blueprint, mem: &mut HashMap<Current, Ans>)
     if current.ore_resources.speed < blueprint.max_robot.needed_ore {
         if let Some(mut new_current) = try_create(&blueprint.ore_robot) {
             new_current.ore_resources.speed += 1;
-            max_geodes = max_geodes.max(run(new_current.clone(), blueprint, mem));
+            max_geodes = max_geodes.max(simulate(new_current.clone(), search));
         }
     }
     if current.clay_resources.speed < blueprint.max_robot.needed_clay {
         if let Some(mut new_current) = try_create(&blueprint.clay_robot) {
             new_current.clay_resources.speed += 1;
-            max_geodes = max_geodes.max(run(new_current.clone(), blueprint, mem));
+            max_geodes = max_geodes.max(simulate(new_current.clone(), search));
         }
     }
     if current.obsidian_resources.speed < blueprint.max_robot.needed_obsidian {
         if let Some(mut new_current) = try_create(&blueprint.obsidian_robot) {
             new_current.obsidian_resources.speed += 1;
-            max_geodes = max_geodes.max(run(new_current.clone(), blueprint, mem));
+            max_geodes = max_geodes.max(simulate(new_current.clone(), search));
         }
     }
     if let Some(mut new_current) = try_create(&blueprint.geode_robot) {
         new_current.geode_resources.speed += 1;
-        max_geodes = max_geodes.max(run(new_current.clone(), blueprint, mem));
+        max_geodes = max_geodes.max(simulate(new_current.clone(), search));
     }
 
-    mem.insert(current, max_geodes);
+    search.best = search.best.max(max_geodes);
+    search.mem.insert(current, max_geodes);
     max_geodes
 }
 
-fn main() {
-    // let input = include_str!("./example.txt");
-    let input = include_str!("./input.txt");
-
-    let mut blueprints: Vec<Blueprint> = Vec::new();
-    for l in input.lines().take(3) {
-        blueprints.push(l.parse().unwrap());
-    }
-
+fn max_geodes(blueprint: &Blueprint, time: i16) -> Ans {
     let current = Current {
-        time_left: 32,
+        time_left: time,
         ore_resources: Resource {
             amount: 0,
             speed: 1,
@@ -222,10 +250,54 @@ fn main() {
         ..Default::default()
     };
 
-    let mut total = 1;
-    for (_, blueprint) in blueprints.iter().enumerate() {
-        let mut mem = HashMap::new();
-        total *= dbg!(run(current.clone(), blueprint, &mut mem));
+    let mut search = Search {
+        blueprint,
+        mem: HashMap::new(),
+        best: 0,
+    };
+    simulate(current, &mut search)
+}
+
+pub struct Day19;
+
+impl Solution for Day19 {
+    type Input = Vec<Blueprint>;
+    type Part1 = i64;
+    type Part2 = i64;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        parse_lines(input, Blueprint::scan)
+    }
+
+    fn part1(input: &Self::Input) -> Self::Part1 {
+        input
+            .iter()
+            .map(|b| b.id as i64 * max_geodes(b, 24) as i64)
+            .sum()
+    }
+
+    fn part2(input: &Self::Input) -> Self::Part2 {
+        let mut total = 1;
+        for blueprint in input.iter().take(3) {
+            total *= max_geodes(blueprint, 32) as i64;
+        }
+        total
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn part1_example() {
+        let input = Day19::parse(EXAMPLE).unwrap();
+        assert_eq!(Day19::part1(&input), 33);
+    }
+
+    #[test]
+    fn part2_example() {
+        let input = Day19::parse(EXAMPLE).unwrap();
+        assert_eq!(Day19::part2(&input), 3472);
     }
-    dbg!(total);
 }
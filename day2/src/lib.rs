@@ -0,0 +1,161 @@
+use common::{parse_lines, ParseError, Solution};
+
+pub const EXAMPLE: &str = include_str!("./example.txt");
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Shape {
+    Rock,
+    Paper,
+    Scissors,
+}
+
+impl Shape {
+    /// The shape this one wins against
+    fn beats(self) -> Self {
+        match self {
+            Shape::Rock => Shape::Scissors,
+            Shape::Paper => Shape::Rock,
+            Shape::Scissors => Shape::Paper,
+        }
+    }
+
+    /// The shape this one loses against
+    fn loses_to(self) -> Self {
+        self.beats().beats()
+    }
+
+    fn score(self) -> u32 {
+        match self {
+            Shape::Rock => 1,
+            Shape::Paper => 2,
+            Shape::Scissors => 3,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Outcome {
+    Lose,
+    Draw,
+    Win,
+}
+
+impl Outcome {
+    fn of(me: Shape, opponent: Shape) -> Self {
+        if me.beats() == opponent {
+            Outcome::Win
+        } else if me == opponent {
+            Outcome::Draw
+        } else {
+            Outcome::Lose
+        }
+    }
+
+    fn score(self) -> u32 {
+        match self {
+            Outcome::Lose => 0,
+            Outcome::Draw => 3,
+            Outcome::Win => 6,
+        }
+    }
+}
+
+/// The second column of the strategy guide, which means something else in each part
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Column {
+    X,
+    Y,
+    Z,
+}
+
+impl Column {
+    /// Part 1 reads the column as the shape to play
+    fn as_shape(self) -> Shape {
+        match self {
+            Column::X => Shape::Rock,
+            Column::Y => Shape::Paper,
+            Column::Z => Shape::Scissors,
+        }
+    }
+
+    /// Part 2 reads the column as how the round has to end
+    fn as_outcome(self) -> Outcome {
+        match self {
+            Column::X => Outcome::Lose,
+            Column::Y => Outcome::Draw,
+            Column::Z => Outcome::Win,
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Round {
+    opponent: Shape,
+    column: Column,
+}
+
+impl Round {
+    fn score(me: Shape, opponent: Shape) -> u32 {
+        me.score() + Outcome::of(me, opponent).score()
+    }
+
+    fn score_as_shape(&self) -> u32 {
+        Round::score(self.column.as_shape(), self.opponent)
+    }
+
+    fn score_as_outcome(&self) -> u32 {
+        let me = match self.column.as_outcome() {
+            Outcome::Lose => self.opponent.beats(),
+            Outcome::Draw => self.opponent,
+            Outcome::Win => self.opponent.loses_to(),
+        };
+        Round::score(me, self.opponent)
+    }
+}
+
+pub struct Day2;
+
+impl Solution for Day2 {
+    type Input = Vec<Round>;
+    type Part1 = u32;
+    type Part2 = u32;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        parse_lines(input, |s| {
+            let opponent = s.one_of(&[
+                ("A", Shape::Rock),
+                ("B", Shape::Paper),
+                ("C", Shape::Scissors),
+            ])?;
+            s.tag(" ")?;
+            let column = s.one_of(&[("X", Column::X), ("Y", Column::Y), ("Z", Column::Z)])?;
+
+            Ok(Round { opponent, column })
+        })
+    }
+
+    fn part1(input: &Self::Input) -> Self::Part1 {
+        input.iter().map(Round::score_as_shape).sum()
+    }
+
+    fn part2(input: &Self::Input) -> Self::Part2 {
+        input.iter().map(Round::score_as_outcome).sum()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn part1_example() {
+        let input = Day2::parse(EXAMPLE).unwrap();
+        assert_eq!(Day2::part1(&input), 15);
+    }
+
+    #[test]
+    fn part2_example() {
+        let input = Day2::parse(EXAMPLE).unwrap();
+        assert_eq!(Day2::part2(&input), 12);
+    }
+}
@@ -0,0 +1,115 @@
+use common::{parse_lines, ParseError, Solution};
+
+pub const EXAMPLE: &str = include_str!("./example.txt");
+
+fn mix(nums: &[i64], key: i64, rounds: usize) -> i64 {
+    let mut nums = nums.iter().map(|n| n * key).collect::<Vec<_>>();
+
+    let mut indexes = (0..nums.len()).collect::<Vec<_>>();
+
+    for _ in 0..rounds {
+        for i in 0..indexes.len() {
+            let index = indexes[i];
+            let mut new_index =
+                (index as i64 + nums[index]).rem_euclid(indexes.len() as i64 - 1) as usize;
+            if new_index == 0 {
+                new_index = indexes.len() - 1;
+            }
+
+            let old_num = nums.remove(index);
+            nums.insert(new_index, old_num);
+
+            let dir = -(new_index as i64 - index as i64).signum();
+
+            for j in indexes.iter_mut() {
+                if index.min(new_index) <= *j && *j <= index.max(new_index) {
+                    *j = j.saturating_add_signed(dir as isize);
+                }
+            }
+            indexes[i] = new_index;
+        }
+    }
+
+    let mut sum = 0;
+    let index_zero = nums
+        .iter()
+        .enumerate()
+        .find_map(|(i, v)| if *v == 0 { Some(i) } else { None })
+        .expect("checked while parsing");
+    for i in &[1000i64, 2000, 3000] {
+        sum += nums[(i + index_zero as i64).rem_euclid(indexes.len() as i64) as usize];
+    }
+    sum
+}
+
+pub struct Day20;
+
+impl Solution for Day20 {
+    type Input = Vec<i64>;
+    type Part1 = i64;
+    type Part2 = i64;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        // the coordinates are counted from the only 0
+        let mut zeros = Vec::new();
+        let mut line = 0;
+        let nums = parse_lines(input, |s| {
+            let at = s.clone();
+            let n = s.number()?;
+            if n == 0 {
+                zeros.push(at.error("only one `0`").offset_line(line));
+            }
+            line += 1;
+            Ok(n)
+        })?;
+
+        let missing =
+            |expected| ParseError::new(expected, "end of input").offset_line(input.lines().count());
+        if nums.len() < 2 {
+            return Err(missing("at least two numbers"));
+        }
+        match zeros.len() {
+            0 => Err(missing("a `0`")),
+            1 => Ok(nums),
+            _ => Err(zeros.swap_remove(1)),
+        }
+    }
+
+    fn part1(input: &Self::Input) -> Self::Part1 {
+        mix(input, 1, 1)
+    }
+
+    fn part2(input: &Self::Input) -> Self::Part2 {
+        mix(input, 811589153, 10)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn part1_example() {
+        let input = Day20::parse(EXAMPLE).unwrap();
+        assert_eq!(Day20::part1(&input), 3);
+    }
+
+    #[test]
+    fn part2_example() {
+        let input = Day20::parse(EXAMPLE).unwrap();
+        assert_eq!(Day20::part2(&input), 1623178306);
+    }
+
+    #[test]
+    fn one_zero() {
+        assert_eq!(
+            Day20::parse("").unwrap_err().expected,
+            "at least two numbers"
+        );
+        assert_eq!(Day20::parse("1\n2\n").unwrap_err().expected, "a `0`");
+
+        let error = Day20::parse("0\n1\n0\n").unwrap_err();
+        assert_eq!((error.line, error.column), (3, 1));
+        assert_eq!(error.expected, "only one `0`");
+    }
+}
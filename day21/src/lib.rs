@@ -0,0 +1,319 @@
+use std::collections::HashMap;
+
+use common::{parse_lines, ParseError, Solution};
+
+pub const EXAMPLE: &str = include_str!("./example.txt");
+
+#[derive(Debug, Clone, PartialEq, PartialOrd, Eq, Ord, Hash)]
+enum Op {
+    Add,
+    Sub,
+    Mul,
+    Div,
+}
+
+impl Op {
+    fn calc(&self, first: i64, second: i64) -> i64 {
+        match self {
+            Op::Add => first + second,
+            Op::Sub => first - second,
+            Op::Mul => first * second,
+            Op::Div => first / second,
+        }
+    }
+
+    fn rev_know_first(&self, target: i64, first: i64) -> i64 {
+        match self {
+            Op::Add => target - first,
+            Op::Sub => -target + first,
+            Op::Mul => target / first,
+            Op::Div => first / target,
+        }
+    }
+
+    fn rev_know_second(&self, target: i64, second: i64) -> i64 {
+        match self {
+            Op::Add => target - second,
+            Op::Sub => target + second,
+            Op::Mul => target / second,
+            Op::Div => target * second,
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, PartialOrd, Eq, Ord, Hash)]
+pub struct Calc {
+    first: String,
+    second: String,
+    op: Op,
+}
+
+fn eval(
+    values: &mut HashMap<String, i64>,
+    calcs: &HashMap<String, Calc>,
+    name: &str,
+) -> Option<i64> {
+    if let Some(v) = values.get(name) {
+        return Some(*v);
+    }
+    if let Some(c) = calcs.get(name) {
+        let first = eval(values, calcs, &c.first);
+        let second = eval(values, calcs, &c.second);
+
+        if let (Some(first), Some(second)) = (first, second) {
+            let v = c.op.calc(first, second);
+            values.insert(name.to_owned(), v);
+            return Some(v);
+        }
+    }
+    None
+}
+
+#[derive(Debug, Clone, PartialEq, PartialOrd, Eq, Ord, Hash)]
+enum CalcTree {
+    Humn,
+    FirstKnow {
+        first: i64,
+        second: Box<CalcTree>,
+        op: Op,
+    },
+    SecondKnow {
+        first: Box<CalcTree>,
+        second: i64,
+        op: Op,
+    },
+}
+
+impl CalcTree {
+    fn find_humn_value(&self, target: i64) -> i64 {
+        match self {
+            CalcTree::Humn => target,
+            CalcTree::FirstKnow { first, second, op } => {
+                let new_target = op.rev_know_first(target, *first);
+                second.find_humn_value(new_target)
+            }
+            CalcTree::SecondKnow { first, second, op } => {
+                let new_target = op.rev_know_second(target, *second);
+                first.find_humn_value(new_target)
+            }
+        }
+    }
+}
+
+fn to_tree(
+    values: &mut HashMap<String, i64>,
+    calcs: &HashMap<String, Calc>,
+    name: &str,
+) -> CalcTree {
+    if name == "humn" {
+        return CalcTree::Humn;
+    }
+
+    let c = calcs.get(name).expect("checked while parsing");
+    if let Some(first) = eval(values, calcs, &c.first) {
+        let second = Box::new(to_tree(values, calcs, &c.second));
+
+        CalcTree::FirstKnow {
+            first,
+            second,
+            op: c.op.clone(),
+        }
+    } else if let Some(second) = eval(values, calcs, &c.second) {
+        let first = Box::new(to_tree(values, calcs, &c.first));
+
+        CalcTree::SecondKnow {
+            first,
+            second,
+            op: c.op.clone(),
+        }
+    } else {
+        unreachable!("`humn` is waited for only once, checked while parsing")
+    }
+}
+
+/// The number of ways `name` waits for `humn`, `Err` with a monkey that ends up waiting for
+/// itself
+fn humn_paths<'a>(
+    calcs: &'a HashMap<String, Calc>,
+    name: &'a str,
+    paths: &mut HashMap<&'a str, Option<usize>>,
+) -> Result<usize, &'a str> {
+    if name == "humn" {
+        return Ok(1);
+    }
+    let Some(c) = calcs.get_key_value(name) else {
+        return Ok(0);
+    };
+    match paths.get(name) {
+        Some(Some(n)) => return Ok(*n),
+        Some(None) => return Err(c.0),
+        None => {}
+    }
+    paths.insert(c.0, None);
+    let first = humn_paths(calcs, &c.1.first, paths)?;
+    let second = humn_paths(calcs, &c.1.second, paths)?;
+    let n = first.saturating_add(second);
+    paths.insert(c.0, Some(n));
+    Ok(n)
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Monkeys {
+    values: HashMap<String, i64>,
+    calcs: HashMap<String, Calc>,
+}
+
+pub struct Day21;
+
+impl Solution for Day21 {
+    type Input = Monkeys;
+    type Part1 = i64;
+    type Part2 = i64;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        let mut values = HashMap::<String, i64>::new();
+        let mut calcs = HashMap::<String, Calc>::new();
+        // the monkeys that are waited for and where they are named
+        let mut waits_for = Vec::new();
+        // the line of every monkey with an operation
+        let mut lines = HashMap::new();
+        let mut line = 0;
+
+        parse_lines(input, |s| {
+            let i = line;
+            line += 1;
+            let name = s.until(": ")?.to_owned();
+            if s.peek().is_some_and(|c| c.is_ascii_digit() || c == '-') {
+                values.insert(name, s.number()?);
+                return Ok(());
+            }
+
+            let first_at = s.clone();
+            let first = s.word()?.to_owned();
+            let op = s.one_of(&[
+                (" + ", Op::Add),
+                (" - ", Op::Sub),
+                (" / ", Op::Div),
+                (" * ", Op::Mul),
+            ])?;
+            let second_at = s.clone();
+            let second = s.word()?.to_owned();
+            waits_for.push((i, first_at, first.clone()));
+            waits_for.push((i, second_at, second.clone()));
+            lines.insert(name.clone(), i);
+
+            calcs.insert(name, Calc { first, second, op });
+            Ok(())
+        })?;
+
+        for (line, at, name) in waits_for {
+            if !values.contains_key(&name) && !calcs.contains_key(&name) {
+                return Err(at.error("a monkey from the list").offset_line(line));
+            }
+        }
+        if !calcs.contains_key("root") {
+            return Err(
+                ParseError::new("a `root` monkey with an operation", "end of input")
+                    .offset_line(input.lines().count()),
+            );
+        }
+        if let Some(line) = lines.get("humn") {
+            return Err(ParseError::new("a number for `humn`", "an operation").offset_line(*line));
+        }
+        if !values.contains_key("humn") {
+            return Err(
+                ParseError::new("a `humn` monkey with a number", "end of input")
+                    .offset_line(input.lines().count()),
+            );
+        }
+
+        let mut paths = HashMap::new();
+        for name in calcs.keys() {
+            if let Err(name) = humn_paths(&calcs, name, &mut paths) {
+                return Err(ParseError::new(
+                    "a monkey that doesn't wait for itself",
+                    format_args!("`{name}`"),
+                )
+                .offset_line(lines[name]));
+            }
+        }
+        let n = paths["root"].expect("all monkeys were checked");
+        if n != 1 {
+            return Err(ParseError::new(
+                "a `root` monkey that waits for `humn` once",
+                format_args!("{n} times"),
+            )
+            .offset_line(lines["root"]));
+        }
+
+        Ok(Monkeys { values, calcs })
+    }
+
+    fn part1(input: &Self::Input) -> Self::Part1 {
+        let mut values = input.values.clone();
+        eval(&mut values, &input.calcs, "root").expect("checked while parsing")
+    }
+
+    fn part2(input: &Self::Input) -> Self::Part2 {
+        let mut values = input.values.clone();
+        values.remove("humn");
+        let calcs = &input.calcs;
+
+        let root = calcs.get("root").expect("checked while parsing");
+        let (root_first, root_second) = (root.first.clone(), root.second.clone());
+
+        let (value_to_get, tree) = match eval(&mut values, calcs, &root_second) {
+            Some(v) => (v, to_tree(&mut values, calcs, &root_first)),
+            None => (
+                eval(&mut values, calcs, &root_first).expect("checked while parsing"),
+                to_tree(&mut values, calcs, &root_second),
+            ),
+        };
+        tree.find_humn_value(value_to_get)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn part1_example() {
+        let input = Day21::parse(EXAMPLE).unwrap();
+        assert_eq!(Day21::part1(&input), 152);
+    }
+
+    #[test]
+    fn part2_example() {
+        let input = Day21::parse(EXAMPLE).unwrap();
+        assert_eq!(Day21::part2(&input), 301);
+    }
+
+    #[test]
+    fn unknown_monkey() {
+        let error = Day21::parse("root: aaaa + bbbb\naaaa: 1").unwrap_err();
+        assert_eq!((error.line, error.column), (1, 14));
+        assert_eq!(error.found, "`bbbb`");
+
+        let error = Day21::parse("aaaa: 1\nroot: 3").unwrap_err();
+        assert_eq!(error.line, 3);
+    }
+
+    #[test]
+    fn bad_monkey_trees() {
+        let error = Day21::parse("root: aaaa + bbbb\naaaa: 1\nbbbb: 2").unwrap_err();
+        assert_eq!(error.expected, "a `humn` monkey with a number");
+        assert_eq!(error.line, 4);
+
+        let error = Day21::parse("root: aaaa + humn\naaaa: humn * humn\nhumn: 2").unwrap_err();
+        assert_eq!(error.found, "3 times");
+        assert_eq!(error.line, 1);
+
+        let error = Day21::parse(
+            "root: aaaa + humn\naaaa: bbbb * cccc\nbbbb: aaaa - cccc\ncccc: 1\nhumn: 2",
+        )
+        .unwrap_err();
+        assert_eq!(error.expected, "a monkey that doesn't wait for itself");
+        assert!([2, 3].contains(&error.line));
+    }
+}
@@ -0,0 +1,371 @@
+use std::{collections::HashMap, error::Error, fmt::Display, io::Write};
+
+use canvas::{Animation, Colour, Display2D};
+use common::{ParseError, Scanner, Solution};
+use geometry::{Grid, Vec2};
+
+pub const EXAMPLE: &str = include_str!("./example.txt");
+
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum Heading {
+    Right = 0,
+    Down = 1,
+    Left = 2,
+    Up = 3,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum Dir {
+    L,
+    R,
+}
+
+impl Heading {
+    fn to_v(&self) -> V {
+        match self {
+            Heading::Right => V::new(1, 0),
+            Heading::Down => V::new(0, 1),
+            Heading::Left => V::new(-1, 0),
+            Heading::Up => V::new(0, -1),
+        }
+    }
+
+    fn add_dir(&mut self, d: &Dir) {
+        let (if_l, if_r) = match self {
+            Heading::Right => (Heading::Up, Heading::Down),
+            Heading::Down => (Heading::Right, Heading::Left),
+            Heading::Left => (Heading::Down, Heading::Up),
+            Heading::Up => (Heading::Left, Heading::Right),
+        };
+        *self = match d {
+            Dir::L => if_l,
+            Dir::R => if_r,
+        };
+    }
+
+    fn to_char(&self) -> char {
+        match self {
+            Heading::Right => '>',
+            Heading::Down => 'v',
+            Heading::Left => '<',
+            Heading::Up => '^',
+        }
+    }
+}
+
+type V = Vec2<i32>;
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum Cell {
+    Void,
+    Open,
+    Wall,
+}
+
+impl TryFrom<char> for Cell {
+    type Error = ParseError;
+
+    fn try_from(c: char) -> Result<Self, Self::Error> {
+        match c {
+            ' ' => Ok(Cell::Void),
+            '.' => Ok(Cell::Open),
+            '#' => Ok(Cell::Wall),
+            c => Err(ParseError::new("`.`, `#` or ` `", format_args!("`{c}`"))),
+        }
+    }
+}
+
+impl Cell {
+    fn to_char(&self) -> char {
+        match self {
+            Cell::Void => ' ',
+            Cell::Open => '.',
+            Cell::Wall => '#',
+        }
+    }
+}
+
+impl Display for Cell {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.to_char())
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum Instruction {
+    Move(i32),
+    Turn(Dir),
+}
+
+type Connections = HashMap<(i32, i32, Heading), (i32, i32, Heading, bool)>;
+
+fn example_connections() -> Connections {
+    HashMap::from([
+        ((2, 0, Heading::Up), (0, 1, Heading::Down, true)),
+        ((2, 0, Heading::Left), (1, 1, Heading::Down, false)),
+        ((2, 0, Heading::Right), (3, 2, Heading::Left, true)),
+        ((0, 1, Heading::Up), (2, 0, Heading::Down, true)),
+        ((0, 1, Heading::Left), (3, 2, Heading::Up, true)),
+        ((0, 1, Heading::Down), (2, 2, Heading::Up, true)),
+        ((1, 1, Heading::Up), (2, 0, Heading::Right, false)),
+        ((1, 1, Heading::Down), (2, 2, Heading::Right, true)),
+        ((2, 1, Heading::Right), (3, 2, Heading::Down, true)),
+        ((2, 2, Heading::Left), (1, 1, Heading::Up, true)),
+        ((2, 2, Heading::Down), (0, 1, Heading::Up, true)),
+        ((3, 2, Heading::Up), (2, 1, Heading::Left, true)),
+        ((3, 2, Heading::Right), (2, 0, Heading::Left, true)),
+        ((3, 2, Heading::Down), (0, 1, Heading::Right, true)),
+    ])
+}
+
+fn input_connections() -> Connections {
+    #[rustfmt::skip]
+    let conections = HashMap::from([
+        ((1i32, 0i32, Heading::Left), (0i32, 2i32, Heading::Right, true)),
+        ((0, 2, Heading::Left), (1, 0, Heading::Right, true)),
+
+        ((1, 0, Heading::Up), (0, 3, Heading::Right, false)),
+        ((0, 3, Heading::Left), (1, 0, Heading::Down, false)),
+
+
+        ((2, 0, Heading::Up), (0, 3, Heading::Up, false)),
+        ((0, 3, Heading::Down), (2, 0, Heading::Down, false)),
+
+        ((2, 0, Heading::Right), (1, 2, Heading::Left, true)),
+        ((1, 2, Heading::Right), (2, 0, Heading::Left, true)),
+
+        ((2, 0, Heading::Down), (1, 1, Heading::Left, false)),
+        ((1, 1, Heading::Right), (2, 0, Heading::Up, false)),
+
+
+        ((1, 1, Heading::Left), (0, 2, Heading::Down, false)),
+        ((0, 2, Heading::Up), (1, 1, Heading::Right, false)),
+
+
+        ((1, 2, Heading::Down), (0, 3, Heading::Left, false)),
+        ((0, 3, Heading::Right), (1, 2, Heading::Up, false)),
+    ]);
+    conections
+}
+
+#[derive(Debug, Clone)]
+pub struct Notes {
+    map: Grid<Cell>,
+    path: Vec<Instruction>,
+    face_size: i32,
+    conections: Connections,
+}
+
+impl Notes {
+    /// The tile at `v`, `None` if it's not on the map
+    fn tile(&self, v: V) -> Option<&Cell> {
+        self.map.get(v).filter(|c| **c != Cell::Void)
+    }
+
+    /// The leftmost open tile of the top row
+    fn start(&self) -> V {
+        let x = self.map.row(0).iter().position(|c| *c == Cell::Open);
+        V::new(x.expect("checked while parsing") as i32, 0)
+    }
+
+    /// Walks the path, returns every position on the way with the heading at that point
+    pub fn walk(&self, cube: bool) -> Vec<(V, Heading)> {
+        let mut pos = self.start();
+        let mut heading = Heading::Right;
+
+        let mut trail = vec![(pos, heading.clone())];
+
+        for i in &self.path {
+            match i {
+                Instruction::Move(a) => {
+                    for _ in 0..*a {
+                        let move_v = heading.to_v();
+                        let (new_pos, new_heading) = match self.tile(pos + move_v) {
+                            Some(_) => (pos + move_v, heading.clone()),
+                            None if cube => self.wrap_cube(&pos, &heading),
+                            None => self.wrap_flat(&pos, &heading),
+                        };
+                        match self.tile(new_pos) {
+                            Some(Cell::Open) => {
+                                pos = new_pos;
+                                heading = new_heading;
+                            }
+                            Some(Cell::Wall) => break,
+                            Some(Cell::Void) | None => unreachable!(),
+                        }
+                        trail.push((pos, heading.clone()));
+                    }
+                }
+                Instruction::Turn(d) => {
+                    heading.add_dir(d);
+                    trail.push((pos, heading.clone()));
+                }
+            }
+        }
+        trail
+    }
+
+    fn wrap_flat(&self, pos: &V, heading: &Heading) -> (V, Heading) {
+        let back = -heading.to_v();
+
+        let mut pos = *pos;
+        while self.tile(pos + back).is_some() {
+            pos += back;
+        }
+        (pos, heading.clone())
+    }
+
+    fn wrap_cube(&self, pos: &V, heading: &Heading) -> (V, Heading) {
+        let face_size = self.face_size;
+
+        let (face_x, face_y) = (pos.x / face_size, pos.y / face_size);
+        let Some((new_face_x, new_face_y, new_heading, invert)) =
+            self.conections.get(&(face_x, face_y, heading.clone()))
+        else {
+            panic!("face_x: {face_x}, face_y: {face_y}, h: {:?}", heading);
+        };
+
+        let mut movable = match heading {
+            Heading::Right | Heading::Left => pos.y - face_y * face_size,
+            Heading::Down | Heading::Up => pos.x - face_x * face_size,
+        };
+        if *invert {
+            movable = face_size - movable - 1;
+        }
+
+        let new_pos = match new_heading {
+            Heading::Right => V::new(new_face_x * face_size, new_face_y * face_size + movable),
+            Heading::Down => V::new(new_face_x * face_size + movable, new_face_y * face_size),
+            Heading::Left => V::new(
+                new_face_x * face_size + face_size - 1,
+                new_face_y * face_size + movable,
+            ),
+            Heading::Up => V::new(
+                new_face_x * face_size + movable,
+                new_face_y * face_size + face_size - 1,
+            ),
+        };
+        (new_pos, new_heading.clone())
+    }
+
+    /// Draws the map with the trail of a walk on top
+    pub fn draw_trail(&self, trail: &[(V, Heading)]) -> Display2D {
+        let mut d = Display2D::new(self.map.width(), self.map.height());
+        for (pos, cell) in self.map.iter() {
+            d.draw(pos, cell.to_char());
+        }
+        for (pos, heading) in trail {
+            d.draw_coloured(*pos, heading.to_char(), Colour::Yellow);
+        }
+        if let Some((pos, heading)) = trail.last() {
+            d.draw_coloured(*pos, heading.to_char(), Colour::Red);
+        }
+        d
+    }
+
+    fn password(trail: &[(V, Heading)]) -> i32 {
+        let (pos, heading) = trail.last().unwrap();
+        (pos.y + 1) * 1000 + (pos.x + 1) * 4 + heading.clone() as i32
+    }
+}
+
+pub struct Day22;
+
+impl Solution for Day22 {
+    type Input = Notes;
+    type Part1 = i32;
+    type Part2 = i32;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        let Some((input_map, input_path)) = input.split_once("\n\n") else {
+            return Err(
+                ParseError::new("an empty line after the map", "end of input")
+                    .offset_line(input.lines().count()),
+            );
+        };
+
+        let map: Grid<Cell> = input_map.parse()?;
+        let (width, height) = (map.width(), map.height());
+        if height == 0 || !map.row(0).contains(&Cell::Open) {
+            return Err(ParseError::new("an open tile `.` in the first row", "none"));
+        }
+
+        let path = Scanner::parse(input_path.trim_end(), |s| {
+            let mut path = Vec::new();
+            loop {
+                path.push(Instruction::Move(s.number()?));
+                if s.is_empty() {
+                    break;
+                }
+                path.push(Instruction::Turn(
+                    s.one_of(&[("R", Dir::R), ("L", Dir::L)])?,
+                ));
+            }
+            Ok(path)
+        })
+        .map_err(|e| e.offset_line(input_map.lines().count() + 1))?;
+
+        // The example is folded out 4 faces wide, my input 3 faces wide
+        let (face_size, conections) = if width > height {
+            ((width / 4) as i32, example_connections())
+        } else {
+            ((width / 3) as i32, input_connections())
+        };
+
+        Ok(Notes {
+            map,
+            path,
+            face_size,
+            conections,
+        })
+    }
+
+    fn part1(input: &Self::Input) -> Self::Part1 {
+        Notes::password(&input.walk(false))
+    }
+
+    fn part2(input: &Self::Input) -> Self::Part2 {
+        Notes::password(&input.walk(true))
+    }
+}
+
+/// Replays the walk of `part`, showing a frame every time the heading changes
+pub fn animate<W: Write>(
+    input: &str,
+    part: u8,
+    animation: &mut Animation<W>,
+) -> Result<(), Box<dyn Error>> {
+    let notes = Day22::parse(input)?;
+    let trail = notes.walk(part == 2);
+
+    for i in 1..trail.len() {
+        if trail[i].1 != trail[i - 1].1 {
+            animation.frame(&notes.draw_trail(&trail[..i]))?;
+        }
+    }
+    animation.frame(&notes.draw_trail(&trail))?;
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn part1_example() {
+        let input = Day22::parse(EXAMPLE).unwrap();
+        assert_eq!(Day22::part1(&input), 6032);
+    }
+
+    #[test]
+    fn part2_example() {
+        let input = Day22::parse(EXAMPLE).unwrap();
+        assert_eq!(Day22::part2(&input), 5031);
+    }
+
+    #[test]
+    fn no_start() {
+        let error = Day22::parse("  #\n  .\n\n1\n").unwrap_err();
+        assert_eq!(error.expected, "an open tile `.` in the first row");
+    }
+}
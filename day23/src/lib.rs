@@ -0,0 +1,142 @@
+use std::collections::{hash_map::Entry, HashMap, HashSet, VecDeque};
+
+use common::{ParseError, Solution};
+use geometry::{Grid, Vec2};
+
+pub const EXAMPLE: &str = include_str!("./example.txt");
+
+type V = Vec2<i32>;
+
+/// Does one round of moving, returns if any elf wanted to move
+fn round(elfs: &mut HashSet<V>, search_dir: &mut VecDeque<V>) -> bool {
+    // This maps new pos to old pos
+    let mut provisions = HashMap::new();
+
+    for elf in elfs.iter() {
+        if !elf.neighbours_diagonal().any(|n| elfs.contains(&n)) {
+            continue;
+        }
+
+        'next_dir: for dir in search_dir.iter() {
+            for other in -1..=1 {
+                let v = if dir.x == 0 {
+                    V::new(other, dir.y) + elf
+                } else {
+                    V::new(dir.x, other) + elf
+                };
+
+                if elfs.contains(&v) {
+                    continue 'next_dir;
+                }
+            }
+            let new_pos = *elf + dir;
+
+            match provisions.entry(new_pos) {
+                Entry::Occupied(mut e) => {
+                    e.insert(None);
+                }
+                Entry::Vacant(e) => {
+                    e.insert(Some(*elf));
+                }
+            }
+            break 'next_dir;
+        }
+    }
+    if provisions.is_empty() {
+        return false;
+    }
+
+    for from in provisions.values().flatten() {
+        elfs.remove(from);
+    }
+    for (to, from) in provisions {
+        if from.is_some() {
+            elfs.insert(to);
+        }
+    }
+
+    search_dir.rotate_left(1);
+    true
+}
+
+fn start_dirs() -> VecDeque<V> {
+    VecDeque::from([V::new(0, -1), V::new(0, 1), V::new(-1, 0), V::new(1, 0)])
+}
+
+pub struct Day23;
+
+impl Solution for Day23 {
+    type Input = HashSet<V>;
+    type Part1 = i32;
+    type Part2 = usize;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        let grove = Grid::parse_with(input, |c| match c {
+            '#' => Ok(true),
+            '.' | ' ' => Ok(false),
+            _ => Err(ParseError::new("`#` or `.`", format_args!("`{c}`"))),
+        })?;
+
+        let elfs: HashSet<V> = grove
+            .iter()
+            .filter(|(_, elf)| **elf)
+            .map(|(v, _)| v)
+            .collect();
+        if elfs.is_empty() {
+            return Err(
+                ParseError::new("an elf, `#`", "end of input").offset_line(input.lines().count())
+            );
+        }
+        Ok(elfs)
+    }
+
+    fn part1(input: &Self::Input) -> Self::Part1 {
+        let mut elfs = input.clone();
+        let mut search_dir = start_dirs();
+
+        for _ in 0..10 {
+            round(&mut elfs, &mut search_dir);
+        }
+
+        let min_x = elfs.iter().map(|e| e.x).min().unwrap();
+        let max_x = elfs.iter().map(|e| e.x).max().unwrap();
+        let min_y = elfs.iter().map(|e| e.y).min().unwrap();
+        let max_y = elfs.iter().map(|e| e.y).max().unwrap();
+
+        (max_x - min_x + 1) * (max_y - min_y + 1) - elfs.len() as i32
+    }
+
+    fn part2(input: &Self::Input) -> Self::Part2 {
+        let mut elfs = input.clone();
+        let mut search_dir = start_dirs();
+
+        let mut steps = 1;
+        while round(&mut elfs, &mut search_dir) {
+            steps += 1;
+        }
+        steps
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn part1_example() {
+        let input = Day23::parse(EXAMPLE).unwrap();
+        assert_eq!(Day23::part1(&input), 110);
+    }
+
+    #[test]
+    fn part2_example() {
+        let input = Day23::parse(EXAMPLE).unwrap();
+        assert_eq!(Day23::part2(&input), 20);
+    }
+
+    #[test]
+    fn no_elves() {
+        assert_eq!(Day23::parse("").unwrap_err().expected, "an elf, `#`");
+        assert_eq!(Day23::parse("...\n...").unwrap_err().line, 3);
+    }
+}
@@ -0,0 +1,225 @@
+use std::{
+    collections::{HashSet, VecDeque},
+    fmt::Display,
+};
+
+use common::{ParseError, Solution};
+use geometry::{Grid, Vec2};
+use num::integer::lcm;
+
+pub const EXAMPLE: &str = include_str!("./example.txt");
+
+type V = Vec2<i32>;
+
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+enum Dir {
+    Up,
+    Right,
+    Down,
+    Left,
+}
+
+impl Dir {
+    fn to_v(&self) -> V {
+        match self {
+            Dir::Up => V::new(0, -1),
+            Dir::Right => V::new(1, 0),
+            Dir::Down => V::new(0, 1),
+            Dir::Left => V::new(-1, 0),
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+struct Blizzard {
+    pos: V,
+    dir: Dir,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+enum Cell {
+    Empty,
+    Filled,
+}
+
+/// Parses the valley without its blizzards, the ground under a blizzard is empty
+impl TryFrom<char> for Cell {
+    type Error = ParseError;
+
+    fn try_from(c: char) -> Result<Self, Self::Error> {
+        match c {
+            '#' => Ok(Cell::Filled),
+            '.' | '^' | '>' | 'v' | '<' => Ok(Cell::Empty),
+            c => Err(ParseError::new(
+                "`#`, `.` or a blizzard",
+                format_args!("`{c}`"),
+            )),
+        }
+    }
+}
+
+impl Display for Cell {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let c = match self {
+            Cell::Empty => '.',
+            Cell::Filled => '#',
+        };
+        write!(f, "{c}")
+    }
+}
+
+type Map = Grid<Cell>;
+
+fn do_breath_first(start: V, start_time: usize, end: V, maps: &[Map]) -> usize {
+    let mut poses = HashSet::from([start]);
+
+    let mut time = start_time;
+    while !poses.contains(&end) {
+        time += 1;
+        let mut new_poses = HashSet::new();
+        for pos in poses {
+            for new_pos in pos.neighbours().chain([pos]) {
+                if maps[time.rem_euclid(maps.len())].get(new_pos) == Some(&Cell::Empty) {
+                    new_poses.insert(new_pos);
+                }
+            }
+        }
+        poses = new_poses;
+    }
+    time
+}
+
+/// Whether `end` can be reached from `start` when leaving at time 0, every position is only
+/// searched once per loop of the blizzards
+fn reachable(start: V, end: V, maps: &[Map]) -> bool {
+    let mut visited = HashSet::from([(start, 0)]);
+    let mut to_search = VecDeque::from([(start, 0)]);
+
+    while let Some((pos, time)) = to_search.pop_front() {
+        if pos == end {
+            return true;
+        }
+        let time = (time + 1) % maps.len();
+        for new_pos in pos.neighbours().chain([pos]) {
+            if maps[time].get(new_pos) == Some(&Cell::Empty) && visited.insert((new_pos, time)) {
+                to_search.push_back((new_pos, time));
+            }
+        }
+    }
+    false
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct Valley {
+    maps: Vec<Map>,
+    start: V,
+    end: V,
+}
+
+pub struct Day24;
+
+impl Solution for Day24 {
+    type Input = Valley;
+    type Part1 = usize;
+    type Part2 = usize;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        let base_map: Map = input.parse()?;
+
+        if base_map.width() < 3 || base_map.height() < 3 {
+            return Err(ParseError::new("a valley inside of walls", "end of input")
+                .offset_line(base_map.height().saturating_sub(1)));
+        }
+        let width = (base_map.width() - 2) as i32;
+        let height = (base_map.height() - 2) as i32;
+
+        let mut blizzards = Vec::new();
+        for (y, l) in input.lines().enumerate() {
+            for (x, c) in l.chars().enumerate() {
+                let dir = match c {
+                    '^' => Dir::Up,
+                    '>' => Dir::Right,
+                    '<' => Dir::Left,
+                    'v' => Dir::Down,
+                    _ => continue,
+                };
+                let pos = V::new(x as i32 - 1, y as i32 - 1);
+                if !(0..width).contains(&pos.x) || !(0..height).contains(&pos.y) {
+                    return Err(ParseError::new("a wall", format_args!("`{c}`")).offset(y, x));
+                }
+                blizzards.push(Blizzard { pos, dir });
+            }
+        }
+
+        let loop_size = lcm(width, height);
+
+        let mut maps = Vec::new();
+        for _ in 0..loop_size {
+            let mut new_map = base_map.clone();
+            for blizzard in &mut blizzards {
+                new_map[V::new(1, 1) + blizzard.pos] = Cell::Filled;
+                blizzard.pos += blizzard.dir.to_v();
+                blizzard.pos.x = blizzard.pos.x.rem_euclid(width);
+                blizzard.pos.y = blizzard.pos.y.rem_euclid(height);
+            }
+            maps.push(new_map);
+        }
+        let mut assert_map = base_map.clone();
+        for blizzard in &blizzards {
+            assert_map[V::new(1, 1) + blizzard.pos] = Cell::Filled;
+        }
+        assert_eq!(maps[0], assert_map);
+
+        // no blizzard reaches the start or the end, so waiting there for the blizzards to loop
+        // around makes both ways reachable from any time
+        let (start, end) = (V::new(1, 0), V::new(width, height + 1));
+        if !reachable(start, end, &maps) || !reachable(end, start, &maps) {
+            return Err(ParseError::new(
+                "a way between the top left and the bottom right of the valley",
+                "none",
+            )
+            .offset_line(base_map.height() - 1));
+        }
+
+        Ok(Valley { maps, start, end })
+    }
+
+    fn part1(input: &Self::Input) -> Self::Part1 {
+        do_breath_first(input.start, 0, input.end, &input.maps)
+    }
+
+    fn part2(input: &Self::Input) -> Self::Part2 {
+        let Valley { maps, start, end } = input;
+
+        let first_time = do_breath_first(*start, 0, *end, maps);
+        let second_time = do_breath_first(*end, first_time, *start, maps);
+        do_breath_first(*start, second_time, *end, maps)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn part1_example() {
+        let input = Day24::parse(EXAMPLE).unwrap();
+        assert_eq!(Day24::part1(&input), 18);
+    }
+
+    #[test]
+    fn part2_example() {
+        let input = Day24::parse(EXAMPLE).unwrap();
+        assert_eq!(Day24::part2(&input), 54);
+    }
+
+    #[test]
+    fn no_way_through() {
+        let error = Day24::parse("#.###\n#...#\n#####").unwrap_err();
+        assert_eq!(error.line, 3);
+        assert_eq!(error.found, "none");
+
+        let error = Day24::parse("#.###\n#.#.#\n###.#").unwrap_err();
+        assert_eq!(error.line, 3);
+    }
+}
@@ -5,32 +5,32 @@ use std::{
     str::FromStr,
 };
 
+use common::{parse_lines, ParseError, Scanner, Solution};
+
+pub const EXAMPLE: &str = include_str!("./example.txt");
+
+#[allow(clippy::upper_case_acronyms)]
 #[derive(Clone, PartialEq, Eq, PartialOrd, Ord)]
-struct SNAFU(Vec<i8>);
+pub struct SNAFU(Vec<i8>);
+
+impl SNAFU {
+    pub fn scan(s: &mut Scanner) -> Result<Self, ParseError> {
+        let digit = [("2", 2), ("1", 1), ("0", 0), ("-", -1), ("=", -2)];
 
-#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord)]
-enum SNAFUError {
-    InvalidDigit(char),
+        let mut snafu = vec![s.one_of(&digit)?];
+        while !s.is_empty() {
+            snafu.push(s.one_of(&digit)?);
+        }
+        snafu.reverse();
+        Ok(SNAFU(snafu))
+    }
 }
 
 impl FromStr for SNAFU {
-    type Err = SNAFUError;
+    type Err = ParseError;
 
     fn from_str(s: &str) -> Result<Self, Self::Err> {
-        let mut snafu = Vec::new();
-        for c in s.chars() {
-            let d = match c {
-                '2' => 2,
-                '1' => 1,
-                '0' => 0,
-                '-' => -1,
-                '=' => -2,
-                _ => return Err(SNAFUError::InvalidDigit(c)),
-            };
-            snafu.push(d);
-        }
-        snafu.reverse();
-        Ok(SNAFU(snafu))
+        Scanner::parse(s, Self::scan)
     }
 }
 
@@ -66,7 +66,7 @@ impl Add<&SNAFU> for &SNAFU {
         for i in 0..(self.0.len() + rhs.0.len()) {
             let a = self.0.get(i);
             let b = rhs.0.get(i);
-            if a == None && b == None {
+            if a.is_none() && b.is_none() {
                 if carry != 0 {
                     result.push(carry);
                 }
@@ -79,7 +79,7 @@ impl Add<&SNAFU> for &SNAFU {
             let mut r = a + b + carry;
             carry = 0;
 
-            while r < -2 || 2 < r {
+            while !(-2..=2).contains(&r) {
                 if r > 0 {
                     r -= 5;
                     carry += 1;
@@ -131,15 +131,39 @@ impl<'a> Sum<&'a SNAFU> for SNAFU {
     }
 }
 
-fn main() {
-    // let input = include_str!("./example.txt");
-    let input = include_str!("./input.txt");
+pub struct Day25;
 
-    let nums = input
-        .lines()
-        .map(|l| l.parse())
-        .flatten()
-        .collect::<Vec<SNAFU>>();
+impl Solution for Day25 {
+    type Input = Vec<SNAFU>;
+    type Part1 = SNAFU;
+    type Part2 = &'static str;
 
-    dbg!(nums.iter().sum::<SNAFU>());
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        parse_lines(input, SNAFU::scan)
+    }
+
+    fn part1(nums: &Self::Input) -> Self::Part1 {
+        nums.iter().sum()
+    }
+
+    fn part2(_: &Self::Input) -> Self::Part2 {
+        "Merry Christmas!"
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn part1_example() {
+        let input = Day25::parse(EXAMPLE).unwrap();
+        assert_eq!(Day25::part1(&input).to_string(), "2=-1=0");
+    }
+
+    #[test]
+    fn part2_example() {
+        let input = Day25::parse(EXAMPLE).unwrap();
+        assert_eq!(Day25::part2(&input), "Merry Christmas!");
+    }
 }
@@ -0,0 +1,205 @@
+use common::{parse_lines, Answer, ParseError, Scanner, Solution};
+
+pub const EXAMPLE: &str = include_str!("./example.txt");
+
+/// The number of elves that share a badge in the puzzle
+const GROUP_SIZE: usize = 3;
+
+fn to_priority(c: char) -> Option<u32> {
+    match c {
+        'a'..='z' => Some(c as u32 - 'a' as u32 + 1),
+        'A'..='Z' => Some(c as u32 - 'A' as u32 + 27),
+        _ => None,
+    }
+}
+
+/// A set of item types with one bit per priority, `a` is the lowest bit and `Z` bit 51
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct Items(u64);
+
+impl Items {
+    const ALL: Items = Items((1 << 52) - 1);
+
+    fn insert(&mut self, priority: u32) {
+        self.0 |= 1 << (priority - 1);
+    }
+
+    #[must_use]
+    fn union(self, other: Self) -> Self {
+        Items(self.0 | other.0)
+    }
+
+    #[must_use]
+    fn intersection(self, other: Self) -> Self {
+        Items(self.0 & other.0)
+    }
+
+    /// The priority of the lowest item in the set
+    fn priority(self) -> Option<u32> {
+        (self.0 != 0).then(|| self.0.trailing_zeros() + 1)
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Rucksack {
+    first: Items,
+    second: Items,
+}
+
+impl Rucksack {
+    pub fn scan(s: &mut Scanner) -> Result<Self, ParseError> {
+        let mut items = Vec::new();
+        while let Some(c) = s.peek() {
+            let priority = to_priority(c).ok_or_else(|| s.error("an item from `a` to `Z`"))?;
+            s.next_char();
+            items.push(priority);
+        }
+        s.end()?;
+
+        if items.len() % 2 != 0 {
+            return Err(s.error("an even number of items"));
+        }
+
+        let (first, second) = items.split_at(items.len() / 2);
+        let compartment = |items: &[u32]| {
+            let mut set = Items::default();
+            for p in items {
+                set.insert(*p);
+            }
+            set
+        };
+
+        Ok(Rucksack {
+            first: compartment(first),
+            second: compartment(second),
+        })
+    }
+
+    fn items(&self) -> Items {
+        self.first.union(self.second)
+    }
+
+    /// The priority of the item that is in both compartments
+    fn misplaced(&self) -> Option<u32> {
+        self.first.intersection(self.second).priority()
+    }
+}
+
+/// The rucksacks, one per line, without checking anything the parts need
+pub fn parse_rucksacks(input: &str) -> Result<Vec<Rucksack>, ParseError> {
+    parse_lines(input, Rucksack::scan)
+}
+
+/// The sum of the priorities of the items that are in both compartments
+///
+/// A rucksack without such an item is an error at its line.
+pub fn misplaced(rucksacks: &[Rucksack]) -> Result<u32, ParseError> {
+    let mut sum = 0;
+    for (i, rucksack) in rucksacks.iter().enumerate() {
+        sum += rucksack.misplaced().ok_or_else(|| {
+            ParseError::new("an item in both compartments", "none").offset_line(i)
+        })?;
+    }
+    Ok(sum)
+}
+
+/// The sum of the priorities of the badges of every group of `group_size` rucksacks
+///
+/// A group without an item that all of its elves carry, or an unfinished last group, is an
+/// error at the line of the first rucksack of the group. Panics if `group_size` is 0.
+pub fn badges(rucksacks: &[Rucksack], group_size: usize) -> Result<u32, ParseError> {
+    assert!(group_size > 0, "a group has at least one elf");
+    let mut sum = 0;
+
+    for (i, group) in rucksacks.chunks(group_size).enumerate() {
+        let line = i * group_size;
+        if group.len() < group_size {
+            return Err(ParseError::new(
+                format_args!("a group of {group_size} rucksacks"),
+                format_args!("{}", group.len()),
+            )
+            .offset_line(line));
+        }
+
+        let common = group
+            .iter()
+            .fold(Items::ALL, |common, r| common.intersection(r.items()));
+        sum += common.priority().ok_or_else(|| {
+            ParseError::new(
+                format_args!("an item in all {group_size} rucksacks of the group"),
+                "none",
+            )
+            .offset_line(line)
+        })?;
+    }
+
+    Ok(sum)
+}
+
+pub struct Day3;
+
+impl Solution for Day3 {
+    type Input = Vec<Rucksack>;
+    type Part1 = Answer<u32>;
+    type Part2 = Answer<u32>;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        parse_rucksacks(input)
+    }
+
+    fn part1(input: &Self::Input) -> Self::Part1 {
+        misplaced(input).into()
+    }
+
+    fn part2(input: &Self::Input) -> Self::Part2 {
+        badges(input, GROUP_SIZE).into()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn part1_example() {
+        let input = Day3::parse(EXAMPLE).unwrap();
+        assert_eq!(Day3::part1(&input), Answer(Ok(157)));
+    }
+
+    #[test]
+    fn part2_example() {
+        let input = Day3::parse(EXAMPLE).unwrap();
+        assert_eq!(Day3::part2(&input), Answer(Ok(70)));
+    }
+
+    #[test]
+    fn invalid_item() {
+        let input = EXAMPLE.replace("PmmdzqPrV", "PmmdzqPr1");
+        let error = Day3::parse(&input).unwrap_err();
+        assert_eq!((error.line, error.column), (3, 9));
+        assert_eq!(error.found, "`1vPwwTWBwg`");
+    }
+
+    #[test]
+    fn one_part_fails() {
+        // the last group is unfinished, but every rucksack has a misplaced item
+        let input = Day3::parse(&EXAMPLE.lines().take(5).collect::<Vec<_>>().join("\n")).unwrap();
+        assert_eq!(Day3::part1(&input), Answer(Ok(16 + 38 + 42 + 22 + 20)));
+        assert_eq!(Day3::part2(&input).0.unwrap_err().line, 4);
+    }
+
+    #[test]
+    fn groups() {
+        let rucksacks = parse_rucksacks(EXAMPLE).unwrap();
+        // pairs can share more than one item, the lowest counts: `f`, `q` and `G`
+        assert_eq!(badges(&rucksacks, 2), Ok(6 + 17 + 33));
+
+        let error = badges(&rucksacks, 6).unwrap_err();
+        assert_eq!(error.line, 1);
+        assert_eq!(error.expected, "an item in all 6 rucksacks of the group");
+
+        let error = badges(&rucksacks[..5], 2).unwrap_err();
+        assert_eq!(error.line, 5);
+        assert_eq!(error.found, "1");
+    }
+}
@@ -0,0 +1,100 @@
+use std::{ops::RangeInclusive, str::FromStr};
+
+use common::{parse_lines, ParseError, Scanner, Solution};
+use geometry::IntervalSet;
+
+pub const EXAMPLE: &str = include_str!("./example.txt");
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Pair {
+    first: IntervalSet<i64>,
+    second: IntervalSet<i64>,
+}
+
+/// Takes a range of sections like `2-4`
+fn scan_sections(s: &mut Scanner) -> Result<RangeInclusive<i64>, ParseError> {
+    let start = s.number()?;
+    s.tag("-")?;
+    let at_end = s.clone();
+    let end = s.number()?;
+    if end < start {
+        return Err(at_end.error(format_args!("a section from {start} on")));
+    }
+    Ok(start..=end)
+}
+
+impl Pair {
+    pub fn scan(s: &mut Scanner) -> Result<Self, ParseError> {
+        let first = scan_sections(s)?.into();
+        s.tag(",")?;
+        let second = scan_sections(s)?.into();
+        Ok(Self { first, second })
+    }
+
+    /// One of the elves has to clean all sections of the other one
+    pub fn fully_contains(&self) -> bool {
+        self.first.difference(&self.second).is_empty()
+            || self.second.difference(&self.first).is_empty()
+    }
+
+    /// The elves have at least one section in common
+    pub fn overlaps(&self) -> bool {
+        !self.first.intersection(&self.second).is_empty()
+    }
+}
+
+impl FromStr for Pair {
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Scanner::parse(s, Self::scan)
+    }
+}
+
+pub struct Day4;
+
+impl Solution for Day4 {
+    type Input = Vec<Pair>;
+    type Part1 = usize;
+    type Part2 = usize;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        parse_lines(input, Pair::scan)
+    }
+
+    fn part1(input: &Self::Input) -> Self::Part1 {
+        input.iter().filter(|p| p.fully_contains()).count()
+    }
+
+    fn part2(input: &Self::Input) -> Self::Part2 {
+        input.iter().filter(|p| p.overlaps()).count()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn part1_example() {
+        let input = Day4::parse(EXAMPLE).unwrap();
+        assert_eq!(Day4::part1(&input), 2);
+    }
+
+    #[test]
+    fn part2_example() {
+        let input = Day4::parse(EXAMPLE).unwrap();
+        assert_eq!(Day4::part2(&input), 4);
+    }
+
+    #[test]
+    fn errors() {
+        let error = Day4::parse(&EXAMPLE.replace("2-8,3-7", "2-8;3-7")).unwrap_err();
+        assert_eq!((error.line, error.column), (4, 4));
+        assert_eq!(error.expected, "`,`");
+
+        let error = Day4::parse(&EXAMPLE.replace("5-7,7-9", "5-7,9-7")).unwrap_err();
+        assert_eq!((error.line, error.column), (3, 7));
+        assert_eq!(error.expected, "a section from 9 on");
+    }
+}
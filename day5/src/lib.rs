@@ -0,0 +1,274 @@
+use std::{error::Error, io::Write};
+
+use canvas::{Animation, Colour, Display2D, YAxis};
+use common::{parse_lines, ParseError, Scanner, Solution};
+use geometry::Vec2;
+
+pub const EXAMPLE: &str = include_str!("./example.txt");
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Move {
+    amount: usize,
+    from: usize,
+    to: usize,
+}
+
+/// The crane that does the rearrangement
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum CrateMover {
+    /// Moves one crate at a time, so the moved crates end up in reverse order
+    Model9000,
+    /// Moves all crates of a move at once and keeps their order
+    Model9001,
+}
+
+impl CrateMover {
+    fn apply(self, staks: &mut [Vec<char>], m: &Move) {
+        let len = staks[m.from].len();
+        let mut moved = staks[m.from].split_off(len - m.amount);
+        if self == CrateMover::Model9000 {
+            moved.reverse();
+        }
+        staks[m.to].extend(moved);
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Procedure {
+    staks: Vec<Vec<char>>,
+    moves: Vec<Move>,
+}
+
+impl Procedure {
+    fn tops(staks: &[Vec<char>]) -> String {
+        staks.iter().filter_map(|s| s.last()).collect()
+    }
+
+    /// Does all moves with `crane`, `each_move` sees the stacks after every move
+    pub fn rearrange(
+        &self,
+        crane: CrateMover,
+        mut each_move: impl FnMut(&Move, &[Vec<char>]),
+    ) -> Vec<Vec<char>> {
+        let mut staks = self.staks.clone();
+
+        for m in &self.moves {
+            crane.apply(&mut staks, m);
+            each_move(m, &staks);
+        }
+
+        staks
+    }
+
+    /// Draws the stacks like the puzzle text does, with room for all crates on one stack
+    pub fn draw(&self, staks: &[Vec<char>], last_move: Option<&Move>) -> Display2D {
+        let crates: usize = self.staks.iter().map(Vec::len).sum();
+        let mut d = Display2D::new(staks.len() * 4 - 1, crates + 1).y_axis(YAxis::Up);
+
+        for (i, stak) in staks.iter().enumerate() {
+            let x = i as i32 * 4;
+            for (j, c) in (i + 1).to_string().chars().enumerate() {
+                d.draw(Vec2::new(x + 1 + j as i32, 0), c);
+            }
+
+            // the crates of the last move are on top of the stack they were moved to
+            let moved = match last_move {
+                Some(m) if m.to == i => stak.len() - m.amount,
+                _ => stak.len(),
+            };
+            for (y, c) in stak.iter().enumerate() {
+                let pos = Vec2::new(x, y as i32 + 1);
+                let colour = if y >= moved {
+                    Colour::Yellow
+                } else {
+                    Colour::White
+                };
+                d.draw_coloured(pos, '[', colour);
+                d.draw_coloured(pos + Vec2::new(1, 0), *c, colour);
+                d.draw_coloured(pos + Vec2::new(2, 0), ']', colour);
+            }
+        }
+        d
+    }
+}
+
+pub struct Day5;
+
+impl Solution for Day5 {
+    type Input = Procedure;
+    type Part1 = String;
+    type Part2 = String;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        let Some((crates, moves)) = input.split_once("\n\n") else {
+            return Err(
+                ParseError::new("an empty line after the crates", "end of input")
+                    .offset_line(input.lines().count()),
+            );
+        };
+        let crate_lines: Vec<&str> = crates.lines().collect();
+        let Some((header, crate_lines)) = crate_lines.split_last() else {
+            return Err(ParseError::new("a row of stack numbers", "end of line"));
+        };
+
+        // every stack is numbered in the last row, the crates sit right above their number
+        let mut columns = Vec::new();
+        Scanner::parse(header, |s| {
+            s.skip_spaces();
+            while !s.is_empty() {
+                let start = s.clone();
+                let n: usize = s.number()?;
+                if n != columns.len() + 1 {
+                    return Err(start.error(format_args!("stack {}", columns.len() + 1)));
+                }
+                columns.push(start.position().1 - 1);
+                s.skip_spaces();
+            }
+            if columns.is_empty() {
+                return Err(s.error("a stack number"));
+            }
+            Ok(())
+        })
+        .map_err(|e| e.offset_line(crate_lines.len()))?;
+
+        let mut staks: Vec<Vec<char>> = vec![vec![]; columns.len()];
+
+        for (y, line) in crate_lines.iter().enumerate().rev() {
+            let line: Vec<char> = line.chars().collect();
+            for (stak, &x) in staks.iter_mut().zip(&columns) {
+                // a crate is a letter in brackets, or there are only spaces
+                let at = |x: Option<usize>| x.and_then(|x| line.get(x)).copied().unwrap_or(' ');
+                let cell = [at(x.checked_sub(1)), at(Some(x)), at(Some(x + 1))];
+                let (expected, found, x) = match cell {
+                    [' ', ' ', ' '] => continue,
+                    ['[', c, ']'] if c.is_alphabetic() => {
+                        stak.push(c);
+                        continue;
+                    }
+                    ['[', c, _] if !c.is_alphabetic() => ("a crate", c, x),
+                    ['[', _, c] => ("`]`", c, x + 1),
+                    [c, ..] => ("`[` or a space", c, x.saturating_sub(1)),
+                };
+                return Err(ParseError::new(expected, format_args!("`{found}`")).offset(y, x));
+            }
+        }
+
+        let stak_count = staks.len();
+        let stak = |s: &mut Scanner| -> Result<usize, ParseError> {
+            let start = s.clone();
+            match s.number::<usize>()? {
+                n @ 1.. if n <= stak_count => Ok(n - 1),
+                _ => Err(start.error(format_args!("a stack from 1 to {stak_count}"))),
+            }
+        };
+
+        // both cranes take the same number of crates, so the heights don't depend on the crane
+        let mut heights: Vec<usize> = staks.iter().map(Vec::len).collect();
+
+        let moves = parse_lines(moves, |s| {
+            s.tag("move ")?;
+            let at_amount = s.clone();
+            let amount = s.number()?;
+            s.tag(" from ")?;
+            let from = stak(s)?;
+            s.tag(" to ")?;
+            let to = stak(s)?;
+
+            if amount > heights[from] {
+                return Err(at_amount.error(format_args!(
+                    "at most the {} crates on stack {}",
+                    heights[from],
+                    from + 1
+                )));
+            }
+            heights[from] -= amount;
+            heights[to] += amount;
+
+            Ok(Move { amount, from, to })
+        })
+        .map_err(|e| e.offset_line(crate_lines.len() + 2))?;
+
+        Ok(Procedure { staks, moves })
+    }
+
+    fn part1(input: &Self::Input) -> Self::Part1 {
+        Procedure::tops(&input.rearrange(CrateMover::Model9000, |_, _| {}))
+    }
+
+    fn part2(input: &Self::Input) -> Self::Part2 {
+        Procedure::tops(&input.rearrange(CrateMover::Model9001, |_, _| {}))
+    }
+}
+
+/// Shows the stacks after every move of the crane of `part`
+pub fn animate<W: Write>(
+    input: &str,
+    part: u8,
+    animation: &mut Animation<W>,
+) -> Result<(), Box<dyn Error>> {
+    let procedure = Day5::parse(input)?;
+    let crane = if part == 1 {
+        CrateMover::Model9000
+    } else {
+        CrateMover::Model9001
+    };
+
+    let mut result = animation.frame(&procedure.draw(&procedure.staks, None));
+    procedure.rearrange(crane, |m, staks| {
+        if result.is_ok() {
+            result = animation.frame(&procedure.draw(staks, Some(m)));
+        }
+    });
+    Ok(result?)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn part1_example() {
+        let input = Day5::parse(EXAMPLE).unwrap();
+        assert_eq!(Day5::part1(&input), "CMZ");
+    }
+
+    #[test]
+    fn part2_example() {
+        let input = Day5::parse(EXAMPLE).unwrap();
+        assert_eq!(Day5::part2(&input), "MCD");
+    }
+
+    const FOUR_STACKS: &str = "    [A]
+[B] [C]     [D]
+ 1   2   3   4
+
+move 2 from 2 to 3
+move 1 from 4 to 1
+";
+
+    #[test]
+    fn four_stacks() {
+        let input = Day5::parse(FOUR_STACKS).unwrap();
+        assert_eq!(Day5::part1(&input), "DC");
+        assert_eq!(Day5::part2(&input), "DA");
+
+        let mut tops = Vec::new();
+        let staks = input.rearrange(CrateMover::Model9000, |m, staks| {
+            tops.push((m.amount, Procedure::tops(staks)));
+        });
+        assert_eq!(tops, [(2, "BCD".to_owned()), (1, "DC".to_owned())]);
+        assert_eq!(staks, [vec!['B', 'D'], vec![], vec!['A', 'C'], vec![]]);
+    }
+
+    #[test]
+    fn crates_in_brackets() {
+        for (crates, column, found) in [("{C}", 1, "`{`"), (" D ", 1, "` `"), ("[E)", 3, "`)`")] {
+            let input = format!("[A] {crates}\n 1   2\n\nmove 1 from 1 to 2\n");
+            let error = Day5::parse(&input).unwrap_err();
+            assert_eq!(
+                (error.line, error.column, error.found.as_str()),
+                (1, column + 4, found)
+            );
+        }
+    }
+}
@@ -0,0 +1,188 @@
+use std::{
+    collections::{HashMap, VecDeque},
+    hash::Hash,
+    io::{self, BufRead},
+};
+
+use common::{Answer, ParseError, Solution};
+
+pub const EXAMPLE: &str = include_str!("./example.txt");
+
+const START_OF_PACKET: usize = 4;
+const START_OF_MESSAGE: usize = 14;
+
+/// Looks for `length` different symbols in a row while the datastream comes in
+///
+/// The window keeps a count per symbol and how many symbols are in it more than once, so
+/// every new symbol is checked in constant time whatever the length.
+#[derive(Debug, Clone)]
+pub struct MarkerDetector<T> {
+    length: usize,
+    window: VecDeque<T>,
+    counts: HashMap<T, usize>,
+    repeated: usize,
+    read: usize,
+}
+
+impl<T: Copy + Eq + Hash> MarkerDetector<T> {
+    /// Panics if `length` is 0, an empty marker would be everywhere.
+    pub fn new(length: usize) -> Self {
+        assert!(length > 0, "a marker is at least one symbol long");
+        Self {
+            length,
+            window: VecDeque::with_capacity(length + 1),
+            counts: HashMap::new(),
+            repeated: 0,
+            read: 0,
+        }
+    }
+
+    /// Adds the next symbol, returns how many symbols have been read if they end in a marker
+    pub fn push(&mut self, symbol: T) -> Option<usize> {
+        self.read += 1;
+
+        self.window.push_back(symbol);
+        let count = self.counts.entry(symbol).or_default();
+        *count += 1;
+        if *count == 2 {
+            self.repeated += 1;
+        }
+
+        if self.window.len() > self.length {
+            let old = self.window.pop_front().unwrap();
+            let count = self.counts.get_mut(&old).unwrap();
+            if *count == 2 {
+                self.repeated -= 1;
+            }
+            *count -= 1;
+        }
+
+        (self.window.len() == self.length && self.repeated == 0).then_some(self.read)
+    }
+}
+
+/// The number of characters up to and including the first marker of `length` characters
+///
+/// The datastream is ASCII, so like [`read_marker`] this looks at bytes: any other character
+/// counts as the bytes it is made of.
+pub fn find_marker(s: &str, length: usize) -> Option<usize> {
+    let mut detector = MarkerDetector::new(length);
+    s.bytes().find_map(|b| detector.push(b))
+}
+
+/// Like [`find_marker`] for the first word of `reader`, but only reads as much as it needs
+pub fn read_marker(reader: impl BufRead, length: usize) -> io::Result<Option<usize>> {
+    let mut detector = MarkerDetector::new(length);
+    let mut started = false;
+    for byte in reader.bytes() {
+        let byte = byte?;
+        if byte.is_ascii_whitespace() {
+            if started {
+                break;
+            }
+            continue;
+        }
+        started = true;
+        if let Some(read) = detector.push(byte) {
+            return Ok(Some(read));
+        }
+    }
+    Ok(None)
+}
+
+/// Like [`find_marker`], with an error at the end of the datastream if there is no marker
+fn marker_answer(datastream: &str, length: usize) -> Answer<usize> {
+    find_marker(datastream, length)
+        .ok_or_else(|| {
+            ParseError::new(
+                format_args!("{length} different characters in a row"),
+                "end of line",
+            )
+            .offset(0, datastream.chars().count())
+        })
+        .into()
+}
+
+pub struct Day6;
+
+impl Solution for Day6 {
+    type Input = String;
+    type Part1 = Answer<usize>;
+    type Part2 = Answer<usize>;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        let datastream = input.trim();
+        if let Some((i, c)) = datastream.chars().enumerate().find(|(_, c)| !c.is_ascii()) {
+            return Err(ParseError::new("an ASCII character", format_args!("`{c}`")).offset(0, i));
+        }
+        Ok(datastream.to_owned())
+    }
+
+    fn part1(input: &Self::Input) -> Self::Part1 {
+        marker_answer(input, START_OF_PACKET)
+    }
+
+    fn part2(input: &Self::Input) -> Self::Part2 {
+        marker_answer(input, START_OF_MESSAGE)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn part1_example() {
+        let input = Day6::parse(EXAMPLE).unwrap();
+        assert_eq!(Day6::part1(&input), Answer(Ok(7)));
+    }
+
+    #[test]
+    fn part2_example() {
+        let input = Day6::parse(EXAMPLE).unwrap();
+        assert_eq!(Day6::part2(&input), Answer(Ok(19)));
+    }
+
+    #[test]
+    fn other_examples() {
+        let examples = [
+            ("bvwbjplbgvbhsrlpgdmjqwftvncz", 5, 23),
+            ("nppdvjthqldpwncqszvftbrmjlhg", 6, 23),
+            ("nznrnfrfntjfmvfwmzdfjlvtqnbhcprsnv", 10, 29),
+            ("zcfzfwzzqfrljwzlrfnpqdbhtmscgvjw", 11, 26),
+        ];
+        for (signal, packet, message) in examples {
+            assert_eq!(find_marker(signal, 4), Some(packet), "{signal}");
+            assert_eq!(find_marker(signal, 14), Some(message), "{signal}");
+            assert_eq!(read_marker(signal.as_bytes(), 14).unwrap(), Some(message));
+        }
+    }
+
+    #[test]
+    fn read_around_whitespace() {
+        let input = "\n  mjqjpqmgbljsphdztnvjfqwrcgsmlb\nabcd";
+        assert_eq!(read_marker(input.as_bytes(), 4).unwrap(), Some(7));
+        assert_eq!(read_marker("\n aab\ncd".as_bytes(), 4).unwrap(), None);
+        assert_eq!(read_marker(input.as_bytes(), 1).unwrap(), Some(1));
+    }
+
+    #[test]
+    #[should_panic = "at least one symbol"]
+    fn empty_marker() {
+        MarkerDetector::<u8>::new(0);
+    }
+
+    #[test]
+    fn only_a_packet_marker() {
+        let input = Day6::parse("abcdabcd").unwrap();
+        assert_eq!(Day6::part1(&input), Answer(Ok(4)));
+        let error = Day6::part2(&input).0.unwrap_err();
+        assert_eq!((error.line, error.column), (1, 9));
+    }
+
+    #[test]
+    fn not_ascii() {
+        let error = Day6::parse("mjqéjpqmgbljsphdztnvjfqwrcgsmlb").unwrap_err();
+        assert_eq!((error.line, error.column), (1, 4));
+    }
+}
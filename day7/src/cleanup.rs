@@ -0,0 +1,194 @@
+use std::{collections::HashMap, fmt::Display};
+
+use crate::{FileSystem, NodeId};
+
+/// How big the disk is and how much of it has to be free
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Disk {
+    pub capacity: u64,
+    pub required: u64,
+}
+
+impl Disk {
+    /// The disk of the device, with room for the update
+    pub const DEVICE: Disk = Disk {
+        capacity: 70000000,
+        required: 30000000,
+    };
+}
+
+/// Directories to delete, none of them inside of another one
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Plan {
+    /// The path and size of every directory
+    pub dirs: Vec<(String, u64)>,
+    pub freed: u64,
+}
+
+impl Display for Plan {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        for (path, size) in &self.dirs {
+            writeln!(f, "{size}\t{path}")?;
+        }
+        write!(f, "{}\ttotal", self.freed)
+    }
+}
+
+/// A set of sums below a limit, one bit per sum
+#[derive(Debug, Clone)]
+struct Sums {
+    words: Vec<u64>,
+    limit: u64,
+}
+
+impl Sums {
+    /// Only the sum 0
+    fn zero(limit: u64) -> Self {
+        let mut words = vec![0; limit.div_ceil(64) as usize];
+        words[0] = 1;
+        Self { words, limit }
+    }
+
+    /// The smallest sum that is at least `min`
+    fn first_from(&self, min: u64) -> Option<u64> {
+        let start = (min / 64) as usize;
+        let mut word = *self.words.get(start)? & (u64::MAX << (min % 64));
+        for i in start.. {
+            if word != 0 {
+                return Some(i as u64 * 64 + word.trailing_zeros() as u64);
+            }
+            word = *self.words.get(i + 1)?;
+        }
+        unreachable!()
+    }
+
+    /// Adds every sum of `other` plus `shift`, calls `added` with the sums that are new
+    fn add_shifted(&mut self, other: &Sums, shift: u64, mut added: impl FnMut(u64)) {
+        let words = (shift / 64) as usize;
+        let bits = shift % 64;
+        for i in words..self.words.len() {
+            let j = i - words;
+            let mut word = other.words[j] << bits;
+            if bits > 0 && j > 0 {
+                word |= other.words[j - 1] >> (64 - bits);
+            }
+            if i + 1 == self.words.len() && !self.limit.is_multiple_of(64) {
+                word &= (1 << (self.limit % 64)) - 1;
+            }
+
+            let mut new = word & !self.words[i];
+            self.words[i] |= new;
+            while new != 0 {
+                added(i as u64 * 64 + new.trailing_zeros() as u64);
+                new &= new - 1;
+            }
+        }
+    }
+}
+
+impl FileSystem {
+    /// How much has to be deleted to have the required space free on `disk`
+    pub fn to_free(&self, disk: Disk) -> u64 {
+        (self.size(Self::ROOT) + disk.required).saturating_sub(disk.capacity)
+    }
+
+    /// The smallest directory that frees enough space on its own, with its path and size
+    ///
+    /// There is none only if the disk is too small even when it is empty.
+    pub fn smallest_dir_to_delete(&self, disk: Disk) -> Option<(String, u64)> {
+        let needed = self.to_free(disk);
+        self.dirs()
+            .map(|(path, dir)| (self.size(dir), path))
+            .filter(|(size, _)| *size >= needed)
+            .min()
+            .map(|(size, path)| (path, size))
+    }
+
+    /// The directories that together free enough space while deleting the least
+    ///
+    /// This is a subset sum over the directories in depth-first order: deleting a directory
+    /// skips everything in it. It takes time in proportion to how much has to be freed, and
+    /// memory of one bit per byte to free for every level of nesting, about 4 MB per level
+    /// for the device.
+    pub fn cleanup_plan(&self, disk: Disk) -> Option<Plan> {
+        let needed = self.to_free(disk);
+        if needed == 0 {
+            return Some(Plan {
+                dirs: Vec::new(),
+                freed: 0,
+            });
+        }
+        if needed > self.size(Self::ROOT) {
+            return None;
+        }
+
+        let dirs: Vec<NodeId> = self.dirs().map(|(_, dir)| dir).collect();
+        let position: HashMap<NodeId, usize> =
+            dirs.iter().enumerate().map(|(i, d)| (*d, i)).collect();
+        // where the directories of a subtree end, counting up from the deepest ones
+        let mut ends: Vec<usize> = (1..=dirs.len()).collect();
+        for i in (1..dirs.len()).rev() {
+            let parent = position[&self.parent(dirs[i]).unwrap()];
+            ends[parent] = ends[parent].max(ends[i]);
+        }
+
+        let (freed, last, mut rest) = self.sweep(&dirs, &ends, needed, |_, _| {})?;
+        let mut chosen = vec![dirs[last]];
+        // the directory that first frees `rest` is part of the plan, going through the
+        // directories again only up to `rest` is cheaper than remembering it for every sum
+        while rest > 0 {
+            let mut by = None;
+            self.sweep(&dirs, &ends, rest + 1, |sum, dir| {
+                if sum == rest {
+                    by = Some(dir);
+                }
+            });
+            let dir = dirs[by.expect("every sum of the plan was freed by a directory")];
+            chosen.push(dir);
+            rest -= self.size(dir);
+        }
+
+        let mut dirs: Vec<(String, u64)> = chosen
+            .into_iter()
+            .map(|d| (self.path(d), self.size(d)))
+            .collect();
+        dirs.sort();
+        Some(Plan { dirs, freed })
+    }
+
+    /// Goes through `dirs` in depth-first order, `ends` are where their subtrees end
+    ///
+    /// Returns the least that frees at least `needed` with the last directory deleted and
+    /// what the ones before it free. `added` is called with every sum below `needed` when a
+    /// directory first makes it possible to free it.
+    fn sweep(
+        &self,
+        dirs: &[NodeId],
+        ends: &[usize],
+        needed: u64,
+        mut added: impl FnMut(u64, usize),
+    ) -> Option<(u64, usize, u64)> {
+        // the sums below `needed` that can be freed by the directories before the current
+        // one, only ever more of them
+        let mut reachable = Sums::zero(needed);
+        // the directories around the current one, with the sums from before them
+        let mut around: Vec<(usize, Sums)> = Vec::new();
+        let mut best: Option<(u64, usize, u64)> = None;
+
+        for i in 0..=dirs.len() {
+            while let Some((dir, before)) = around.pop_if(|(dir, _)| ends[*dir] == i) {
+                let size = self.size(dirs[dir]);
+                if let Some(rest) = before.first_from(needed.saturating_sub(size)) {
+                    if best.is_none_or(|(freed, _, _)| rest + size < freed) {
+                        best = Some((rest + size, dir, rest));
+                    }
+                }
+                reachable.add_shifted(&before, size, |sum| added(sum, dir));
+            }
+            if i < dirs.len() {
+                around.push((i, reachable.clone()));
+            }
+        }
+        best
+    }
+}
@@ -0,0 +1,195 @@
+use std::{cell::Cell, collections::BTreeMap};
+
+/// Points at a file or directory of a [`FileSystem`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct NodeId(usize);
+
+#[derive(Debug, Clone)]
+enum Kind {
+    File { size: u64 },
+    Dir { children: BTreeMap<String, NodeId> },
+}
+
+#[derive(Debug, Clone)]
+struct Node {
+    name: String,
+    parent: Option<NodeId>,
+    kind: Kind,
+    cached_size: Cell<Option<u64>>,
+}
+
+/// A directory tree stored in one list, the nodes point at each other by index.
+///
+/// The size of a directory is worked out when it is first asked for and kept until something
+/// below it changes.
+#[derive(Debug, Clone)]
+pub struct FileSystem {
+    nodes: Vec<Node>,
+}
+
+impl Default for FileSystem {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl FileSystem {
+    pub const ROOT: NodeId = NodeId(0);
+
+    pub fn new() -> Self {
+        let root = Node {
+            name: String::new(),
+            parent: None,
+            kind: Kind::Dir {
+                children: BTreeMap::new(),
+            },
+            cached_size: Cell::new(None),
+        };
+        Self { nodes: vec![root] }
+    }
+
+    fn node(&self, id: NodeId) -> &Node {
+        &self.nodes[id.0]
+    }
+
+    pub fn name(&self, id: NodeId) -> &str {
+        &self.node(id).name
+    }
+
+    pub fn parent(&self, id: NodeId) -> Option<NodeId> {
+        self.node(id).parent
+    }
+
+    pub fn is_dir(&self, id: NodeId) -> bool {
+        matches!(self.node(id).kind, Kind::Dir { .. })
+    }
+
+    /// The files and directories right inside of `dir` by name, nothing for a file.
+    pub fn children(&self, dir: NodeId) -> impl Iterator<Item = (&str, NodeId)> {
+        let children = match &self.node(dir).kind {
+            Kind::Dir { children } => Some(children),
+            Kind::File { .. } => None,
+        };
+        children
+            .into_iter()
+            .flatten()
+            .map(|(name, id)| (name.as_str(), *id))
+    }
+
+    pub fn child(&self, dir: NodeId, name: &str) -> Option<NodeId> {
+        match &self.node(dir).kind {
+            Kind::Dir { children } => children.get(name).copied(),
+            Kind::File { .. } => None,
+        }
+    }
+
+    /// Finds an absolute path like `/a/e`.
+    pub fn lookup(&self, path: &str) -> Option<NodeId> {
+        path.split('/')
+            .filter(|name| !name.is_empty())
+            .try_fold(Self::ROOT, |dir, name| self.child(dir, name))
+    }
+
+    /// The absolute path of `id`, `/` for the root.
+    pub fn path(&self, id: NodeId) -> String {
+        let mut names = Vec::new();
+        let mut at = id;
+        while let Some(parent) = self.parent(at) {
+            names.push(self.name(at));
+            at = parent;
+        }
+        names.reverse();
+        format!("/{}", names.join("/"))
+    }
+
+    /// Forgets the size of `id` and of every directory it is in.
+    fn invalidate(&self, id: NodeId) {
+        let mut at = Some(id);
+        while let Some(id) = at {
+            self.node(id).cached_size.set(None);
+            at = self.parent(id);
+        }
+    }
+
+    fn add(&mut self, dir: NodeId, name: &str, kind: Kind) -> NodeId {
+        let id = NodeId(self.nodes.len());
+        let Kind::Dir { children } = &mut self.nodes[dir.0].kind else {
+            panic!("{} is not a directory", self.path(dir));
+        };
+        children.insert(name.to_owned(), id);
+        self.nodes.push(Node {
+            name: name.to_owned(),
+            parent: Some(dir),
+            kind,
+            cached_size: Cell::new(None),
+        });
+        self.invalidate(dir);
+        id
+    }
+
+    /// Adds the directory `name` to `dir`, or returns it if it is already there.
+    ///
+    /// Panics if `dir` is a file or `name` is a file in it.
+    pub fn add_dir(&mut self, dir: NodeId, name: &str) -> NodeId {
+        match self.child(dir, name) {
+            Some(id) if self.is_dir(id) => id,
+            Some(id) => panic!("{} is a file", self.path(id)),
+            None => self.add(
+                dir,
+                name,
+                Kind::Dir {
+                    children: BTreeMap::new(),
+                },
+            ),
+        }
+    }
+
+    /// Adds the file `name` to `dir`, a file that is already there gets the new size.
+    ///
+    /// Panics if `dir` is a file or `name` is a directory in it.
+    pub fn add_file(&mut self, dir: NodeId, name: &str, size: u64) -> NodeId {
+        let Some(id) = self.child(dir, name) else {
+            return self.add(dir, name, Kind::File { size });
+        };
+        let path = self.path(id);
+        match &mut self.nodes[id.0].kind {
+            Kind::File { size: old } => *old = size,
+            Kind::Dir { .. } => panic!("{path} is a directory"),
+        }
+        self.invalidate(id);
+        id
+    }
+
+    /// The size of a file, or of everything in a directory.
+    pub fn size(&self, id: NodeId) -> u64 {
+        let node = self.node(id);
+        if let Some(size) = node.cached_size.get() {
+            return size;
+        }
+
+        let size = match &node.kind {
+            Kind::File { size } => *size,
+            Kind::Dir { children } => children.values().map(|c| self.size(*c)).sum(),
+        };
+        node.cached_size.set(Some(size));
+        size
+    }
+
+    /// Every file and directory below `id` and `id` itself, depth first and by name.
+    pub fn walk(&self, id: NodeId) -> impl Iterator<Item = NodeId> + '_ {
+        let mut stack = vec![id];
+        std::iter::from_fn(move || {
+            let id = stack.pop()?;
+            let children: Vec<NodeId> = self.children(id).map(|(_, c)| c).collect();
+            stack.extend(children.into_iter().rev());
+            Some(id)
+        })
+    }
+
+    /// Every directory with its absolute path, starting at the root.
+    pub fn dirs(&self) -> impl Iterator<Item = (String, NodeId)> + '_ {
+        self.walk(Self::ROOT)
+            .filter(|id| self.is_dir(*id))
+            .map(|id| (self.path(id), id))
+    }
+}
@@ -0,0 +1,166 @@
+mod cleanup;
+mod filesystem;
+mod render;
+mod transcript;
+
+use common::{ParseError, Solution};
+
+pub use cleanup::{Disk, Plan};
+pub use filesystem::{FileSystem, NodeId};
+pub use transcript::{replay, Issue, Warning};
+
+pub const EXAMPLE: &str = include_str!("./example.txt");
+
+pub struct Day7;
+
+impl Solution for Day7 {
+    type Input = FileSystem;
+    type Part1 = u64;
+    type Part2 = u64;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        transcript::replay(input).map(|(fs, _)| fs)
+    }
+
+    fn part1(fs: &Self::Input) -> Self::Part1 {
+        fs.dirs()
+            .map(|(_, dir)| fs.size(dir))
+            .filter(|size| *size <= 100000)
+            .sum()
+    }
+
+    fn part2(fs: &Self::Input) -> Self::Part2 {
+        let (_, size) = fs
+            .smallest_dir_to_delete(Disk::DEVICE)
+            .expect("the update fits on an empty disk");
+        size
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn part1_example() {
+        let input = Day7::parse(EXAMPLE).unwrap();
+        assert_eq!(Day7::part1(&input), 95437);
+    }
+
+    #[test]
+    fn part2_example() {
+        let input = Day7::parse(EXAMPLE).unwrap();
+        assert_eq!(Day7::part2(&input), 24933642);
+    }
+
+    #[test]
+    fn sizes_follow_changes() {
+        let mut fs = Day7::parse(EXAMPLE).unwrap();
+        let e = fs.lookup("/a/e").unwrap();
+        assert_eq!(fs.path(e), "/a/e");
+        assert_eq!(fs.size(e), 584);
+        assert_eq!(fs.size(FileSystem::ROOT), 48381165);
+
+        fs.add_file(e, "j", 16);
+        assert_eq!(fs.size(fs.lookup("/a").unwrap()), 94853 + 16);
+        assert_eq!(fs.size(FileSystem::ROOT), 48381165 + 16);
+
+        let dirs: Vec<String> = fs.dirs().map(|(path, _)| path).collect();
+        assert_eq!(dirs, ["/", "/a", "/a/e", "/d"]);
+    }
+
+    #[test]
+    fn cleanup_plan() {
+        let fs = Day7::parse(EXAMPLE).unwrap();
+        let disk = Disk {
+            capacity: 48381165 + 100000,
+            required: 100000 + 10000,
+        };
+        assert_eq!(fs.to_free(disk), 10000);
+        assert_eq!(
+            fs.smallest_dir_to_delete(disk),
+            Some(("/a".to_owned(), 94853))
+        );
+        let plan = fs.cleanup_plan(disk).unwrap();
+        assert_eq!(plan.dirs, [("/a".to_owned(), 94853)]);
+
+        let plan = fs.cleanup_plan(Disk::DEVICE).unwrap();
+        assert_eq!(plan.dirs, [("/d".to_owned(), 24933642)]);
+        assert_eq!(plan.freed, 24933642);
+
+        let small = Disk {
+            capacity: 10,
+            required: 20,
+        };
+        assert_eq!(fs.smallest_dir_to_delete(small), None);
+        assert_eq!(fs.cleanup_plan(small), None);
+
+        let roomy = Disk {
+            capacity: 100000000,
+            required: 10,
+        };
+        assert_eq!(fs.cleanup_plan(roomy).unwrap().freed, 0);
+    }
+
+    #[test]
+    fn plan_with_several_dirs() {
+        let mut fs = FileSystem::new();
+        let a = fs.add_dir(FileSystem::ROOT, "a");
+        let b = fs.add_dir(FileSystem::ROOT, "b");
+        let c = fs.add_dir(b, "c");
+        fs.add_file(a, "x", 40);
+        fs.add_file(b, "y", 100);
+        fs.add_file(c, "z", 30);
+        let disk = Disk {
+            capacity: 170,
+            required: 70,
+        };
+        assert_eq!(fs.to_free(disk), 70);
+        assert_eq!(
+            fs.smallest_dir_to_delete(disk),
+            Some(("/b".to_owned(), 130))
+        );
+        let plan = fs.cleanup_plan(disk).unwrap();
+        assert_eq!(plan.dirs, [("/a".to_owned(), 40), ("/b/c".to_owned(), 30)]);
+        assert_eq!(plan.freed, 70);
+        assert_eq!(plan.to_string(), "40\t/a\n30\t/b/c\n70\ttotal");
+    }
+
+    #[test]
+    fn warnings() {
+        let log = "\
+$ cd /
+$ ls
+dir a
+10 b
+$ cd a
+$ ls
+20 c
+$ cd ..
+$ ls
+dir a
+11 b
+5 x
+$ cd e
+$ cd ..
+$ cd a
+30 c
+";
+        let (fs, warnings) = replay(log).unwrap();
+        let warnings: Vec<String> = warnings.iter().map(|w| w.to_string()).collect();
+        assert_eq!(
+            warnings,
+            [
+                "line 9: / is listed with other contents than on line 2",
+                "line 11: /b has size 11, but had size 10 on line 4",
+                "line 13: cd into /e, which no listing has shown",
+                "line 13: /e is never listed, its size is only a lower bound",
+                "line 16: ls output without a `$ ls` before it",
+                "line 16: /a/c has size 30, but had size 20 on line 7",
+            ]
+        );
+        assert_eq!(fs.size(FileSystem::ROOT), 46);
+
+        assert!(replay(EXAMPLE).unwrap().1.is_empty());
+    }
+}
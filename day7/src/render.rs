@@ -0,0 +1,215 @@
+use std::fmt::Write;
+
+use crate::{FileSystem, NodeId};
+
+/// A size like `du -h` shows it: rounded up, with one decimal below 10 of a unit.
+fn human(size: u64) -> String {
+    const UNITS: [&str; 5] = ["K", "M", "G", "T", "P"];
+    if size < 1024 {
+        return size.to_string();
+    }
+
+    let mut value = size as f64;
+    for (i, unit) in UNITS.iter().enumerate() {
+        value /= 1024.0;
+        let tenths = (value * 10.0).ceil() / 10.0;
+        let rounded = if tenths < 10.0 { tenths } else { value.ceil() };
+        // a value that rounds up to 1024 is shown in the next unit
+        if rounded < 1024.0 || i == UNITS.len() - 1 {
+            return if rounded < 10.0 {
+                format!("{rounded:.1}{unit}")
+            } else {
+                format!("{rounded}{unit}")
+            };
+        }
+    }
+    unreachable!("the last unit takes any value")
+}
+
+fn json_string(s: &str) -> String {
+    let mut json = String::from('"');
+    for c in s.chars() {
+        match c {
+            '"' => json.push_str("\\\""),
+            '\\' => json.push_str("\\\\"),
+            '\n' => json.push_str("\\n"),
+            '\r' => json.push_str("\\r"),
+            '\t' => json.push_str("\\t"),
+            c if c.is_control() => write!(json, "\\u{:04x}", c as u32).unwrap(),
+            c => json.push(c),
+        }
+    }
+    json.push('"');
+    json
+}
+
+impl FileSystem {
+    /// Draws the tree like `tree` does, with the size after every name.
+    pub fn tree(&self) -> String {
+        let mut out = format!("/ ({})\n", self.size(Self::ROOT));
+        self.tree_below(Self::ROOT, "", &mut out);
+        out
+    }
+
+    fn tree_below(&self, dir: NodeId, indent: &str, out: &mut String) {
+        let children: Vec<_> = self.children(dir).collect();
+        for (i, (name, id)) in children.iter().enumerate() {
+            let last = i + 1 == children.len();
+            let (branch, more) = if last {
+                ("└── ", "    ")
+            } else {
+                ("├── ", "│   ")
+            };
+            let slash = if self.is_dir(*id) { "/" } else { "" };
+            writeln!(out, "{indent}{branch}{name}{slash} ({})", self.size(*id)).unwrap();
+            self.tree_below(*id, &format!("{indent}{more}"), out);
+        }
+    }
+
+    /// Lists every directory like `du -h`, the biggest first.
+    pub fn du(&self) -> String {
+        let mut dirs: Vec<(u64, String)> = self
+            .dirs()
+            .map(|(path, id)| (self.size(id), path))
+            .collect();
+        dirs.sort_by(|(a_size, a_path), (b_size, b_path)| {
+            b_size.cmp(a_size).then_with(|| a_path.cmp(b_path))
+        });
+
+        let mut out = String::new();
+        for (size, path) in dirs {
+            writeln!(out, "{}\t{path}", human(size)).unwrap();
+        }
+        out
+    }
+
+    /// The whole hierarchy as JSON, every node has a `name`, a `type` and a `size`, and
+    /// directories their `children`.
+    pub fn to_json(&self) -> String {
+        let mut out = String::new();
+        self.json_node(Self::ROOT, 0, &mut out);
+        out.push('\n');
+        out
+    }
+
+    fn json_node(&self, id: NodeId, depth: usize, out: &mut String) {
+        let indent = "  ".repeat(depth);
+        let name = if id == Self::ROOT { "/" } else { self.name(id) };
+        let kind = if self.is_dir(id) { "dir" } else { "file" };
+
+        write!(
+            out,
+            "{{\n{indent}  \"name\": {},\n{indent}  \"type\": \"{kind}\",\n{indent}  \"size\": {}",
+            json_string(name),
+            self.size(id)
+        )
+        .unwrap();
+
+        if self.is_dir(id) {
+            out.push_str(&format!(",\n{indent}  \"children\": ["));
+            for (i, (_, child)) in self.children(id).enumerate() {
+                if i > 0 {
+                    out.push(',');
+                }
+                write!(out, "\n{indent}    ").unwrap();
+                self.json_node(child, depth + 2, out);
+            }
+            if self.children(id).next().is_some() {
+                write!(out, "\n{indent}  ").unwrap();
+            }
+            out.push(']');
+        }
+        write!(out, "\n{indent}}}").unwrap();
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use common::Solution;
+
+    use crate::{Day7, FileSystem, EXAMPLE};
+
+    #[test]
+    fn human_sizes() {
+        assert_eq!(super::human(584), "584");
+        assert_eq!(super::human(29116), "29K");
+        assert_eq!(super::human(1536), "1.5K");
+        assert_eq!(super::human(48381165), "47M");
+
+        assert_eq!(super::human(1024), "1.0K");
+        assert_eq!(super::human(10188), "10K");
+        assert_eq!(super::human(10239), "10K");
+        assert_eq!(super::human(1048575), "1.0M");
+        assert_eq!(super::human(1048576), "1.0M");
+        assert_eq!(super::human(1073741823), "1.0G");
+    }
+
+    #[test]
+    fn tree_of_the_example() {
+        let fs = Day7::parse(EXAMPLE).unwrap();
+        assert_eq!(
+            fs.tree(),
+            "\
+/ (48381165)
+├── a/ (94853)
+│   ├── e/ (584)
+│   │   └── i (584)
+│   ├── f (29116)
+│   ├── g (2557)
+│   └── h.lst (62596)
+├── b.txt (14848514)
+├── c.dat (8504156)
+└── d/ (24933642)
+    ├── d.ext (5626152)
+    ├── d.log (8033020)
+    ├── j (4060174)
+    └── k (7214296)
+"
+        );
+    }
+
+    #[test]
+    fn json() {
+        let fs = Day7::parse(EXAMPLE).unwrap();
+        let json = fs.to_json();
+        assert!(
+            json.starts_with("{\n  \"name\": \"/\",\n  \"type\": \"dir\",\n  \"size\": 48381165,")
+        );
+        assert_eq!(json.matches("\"type\": \"file\"").count(), 10);
+        assert_eq!(json.matches("\"type\": \"dir\"").count(), 4);
+
+        let mut fs = FileSystem::new();
+        let dir = fs.add_dir(FileSystem::ROOT, "say \"hi\"");
+        fs.add_file(dir, "back\\slash\t\u{1}", 5);
+        fs.add_dir(FileSystem::ROOT, "empty");
+        assert_eq!(
+            fs.to_json(),
+            r#"{
+  "name": "/",
+  "type": "dir",
+  "size": 5,
+  "children": [
+    {
+      "name": "empty",
+      "type": "dir",
+      "size": 0,
+      "children": []
+    },
+    {
+      "name": "say \"hi\"",
+      "type": "dir",
+      "size": 5,
+      "children": [
+        {
+          "name": "back\\slash\t\u0001",
+          "type": "file",
+          "size": 5
+        }
+      ]
+    }
+  ]
+}
+"#
+        );
+    }
+}
@@ -0,0 +1,279 @@
+use std::{
+    collections::{BTreeMap, HashMap},
+    fmt::Display,
+};
+
+use common::{parse_lines, ParseError, Scanner};
+
+use crate::{FileSystem, NodeId};
+
+/// One line of the terminal output
+enum Line<'a> {
+    Cd(&'a str),
+    Ls,
+    Dir(&'a str),
+    File(u64, &'a str),
+}
+
+fn name<'a>(s: &mut Scanner<'a>) -> Result<&'a str, ParseError> {
+    if s.is_empty() {
+        return Err(s.error("a name"));
+    }
+    Ok(s.take_rest())
+}
+
+fn scan_line<'a>(s: &mut Scanner<'a>) -> Result<Line<'a>, ParseError> {
+    if s.try_tag("$ ") {
+        if s.one_of(&[("cd ", true), ("ls", false)])? {
+            Ok(Line::Cd(name(s)?))
+        } else {
+            Ok(Line::Ls)
+        }
+    } else if s.try_tag("dir ") {
+        Ok(Line::Dir(name(s)?))
+    } else {
+        let size = s.number()?;
+        s.tag(" ")?;
+        Ok(Line::File(size, name(s)?))
+    }
+}
+
+/// Something in the terminal output that doesn't add up, the filesystem is built anyway
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Issue {
+    /// A file was listed again with another size, the last size is used
+    SizeChanged {
+        path: String,
+        size: u64,
+        earlier: u64,
+        earlier_line: usize,
+    },
+    /// A directory was listed again with other names in it
+    ListingChanged { path: String, earlier_line: usize },
+    /// `cd` into a directory that no listing has shown
+    CdIntoUnlisted { path: String },
+    /// A directory that was never listed, its size is only a lower bound
+    NeverListed { path: String },
+    /// Output of `ls` without a `$ ls` before it
+    OutputWithoutLs,
+}
+
+/// An [`Issue`] and the line it is on, starting at 1
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Warning {
+    pub line: usize,
+    pub issue: Issue,
+}
+
+impl Display for Warning {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "line {}: ", self.line)?;
+        match &self.issue {
+            Issue::SizeChanged {
+                path,
+                size,
+                earlier,
+                earlier_line,
+            } => write!(
+                f,
+                "{path} has size {size}, but had size {earlier} on line {earlier_line}"
+            ),
+            Issue::ListingChanged { path, earlier_line } => write!(
+                f,
+                "{path} is listed with other contents than on line {earlier_line}"
+            ),
+            Issue::CdIntoUnlisted { path } => {
+                write!(f, "cd into {path}, which no listing has shown")
+            }
+            Issue::NeverListed { path } => {
+                write!(f, "{path} is never listed, its size is only a lower bound")
+            }
+            Issue::OutputWithoutLs => write!(f, "ls output without a `$ ls` before it"),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Entry {
+    Dir,
+    File(u64),
+}
+
+/// One run of `ls`, the entries keep the line they are on
+struct Listing {
+    dir: NodeId,
+    line: usize,
+    entries: BTreeMap<String, (Entry, usize)>,
+}
+
+/// Walks through the terminal output and keeps track of what has been seen where
+struct Replay {
+    fs: FileSystem,
+    cwd: NodeId,
+    listing: Option<Listing>,
+    first_listings: HashMap<NodeId, Listing>,
+    first_seen: HashMap<NodeId, usize>,
+    warnings: Vec<Warning>,
+}
+
+impl Replay {
+    fn warn(&mut self, line: usize, issue: Issue) {
+        self.warnings.push(Warning { line, issue });
+    }
+
+    /// Compares a finished listing with the first listing of the same directory
+    fn end_listing(&mut self) {
+        let Some(listing) = self.listing.take() else {
+            return;
+        };
+        let Some(first) = self.first_listings.get(&listing.dir) else {
+            self.first_listings.insert(listing.dir, listing);
+            return;
+        };
+
+        let mut warnings = Vec::new();
+        if !listing.entries.keys().eq(first.entries.keys()) {
+            warnings.push(Warning {
+                line: listing.line,
+                issue: Issue::ListingChanged {
+                    path: self.fs.path(listing.dir),
+                    earlier_line: first.line,
+                },
+            });
+        }
+        for (name, (entry, line)) in &listing.entries {
+            if let (Entry::File(size), Some((Entry::File(earlier), earlier_line))) =
+                (entry, first.entries.get(name))
+            {
+                if size != earlier {
+                    let id = self.fs.child(listing.dir, name).unwrap();
+                    warnings.push(Warning {
+                        line: *line,
+                        issue: Issue::SizeChanged {
+                            path: self.fs.path(id),
+                            size: *size,
+                            earlier: *earlier,
+                            earlier_line: *earlier_line,
+                        },
+                    });
+                }
+            }
+        }
+        self.warnings.extend(warnings);
+    }
+
+    /// Adds an entry to the current listing, `line` starts at 1
+    fn list(&mut self, line: usize, name: &str, entry: Entry) {
+        if !matches!(&self.listing, Some(listing) if listing.dir == self.cwd) {
+            self.warn(line, Issue::OutputWithoutLs);
+            self.end_listing();
+            self.listing = Some(Listing {
+                dir: self.cwd,
+                line,
+                entries: BTreeMap::new(),
+            });
+        }
+        if let Some(listing) = &mut self.listing {
+            listing.entries.insert(name.to_owned(), (entry, line));
+        }
+    }
+
+    fn see(&mut self, id: NodeId, line: usize) {
+        self.first_seen.entry(id).or_insert(line);
+    }
+
+    fn step(&mut self, i: usize, line: Line) -> Result<(), ParseError> {
+        let error =
+            |expected: &str, found: String| Err(ParseError::new(expected, found).offset_line(i));
+        match line {
+            Line::Cd(_) | Line::Ls => self.end_listing(),
+            Line::Dir(_) | Line::File(..) => {}
+        }
+
+        match line {
+            Line::Cd("/") => self.cwd = FileSystem::ROOT,
+            Line::Cd("..") => match self.fs.parent(self.cwd) {
+                Some(parent) => self.cwd = parent,
+                None => return error("a directory to leave", "`/`".to_owned()),
+            },
+            Line::Cd(name) => match self.fs.child(self.cwd, name) {
+                Some(file) if !self.fs.is_dir(file) => {
+                    return error("a directory", format!("the file `{}`", self.fs.path(file)))
+                }
+                Some(dir) => self.cwd = dir,
+                None => {
+                    self.cwd = self.fs.add_dir(self.cwd, name);
+                    let path = self.fs.path(self.cwd);
+                    self.see(self.cwd, i + 1);
+                    self.warn(i + 1, Issue::CdIntoUnlisted { path });
+                }
+            },
+            Line::Ls => {
+                self.listing = Some(Listing {
+                    dir: self.cwd,
+                    line: i + 1,
+                    entries: BTreeMap::new(),
+                });
+            }
+            Line::Dir(name) => {
+                match self.fs.child(self.cwd, name) {
+                    Some(file) if !self.fs.is_dir(file) => {
+                        return error("a directory", format!("the file `{}`", self.fs.path(file)))
+                    }
+                    _ => {
+                        let dir = self.fs.add_dir(self.cwd, name);
+                        self.see(dir, i + 1);
+                    }
+                }
+                self.list(i + 1, name, Entry::Dir);
+            }
+            Line::File(size, name) => {
+                match self.fs.child(self.cwd, name) {
+                    Some(dir) if self.fs.is_dir(dir) => {
+                        return error("a file", format!("the directory `{}`", self.fs.path(dir)))
+                    }
+                    _ => {
+                        self.fs.add_file(self.cwd, name, size);
+                    }
+                }
+                self.list(i + 1, name, Entry::File(size));
+            }
+        }
+        Ok(())
+    }
+}
+
+/// Rebuilds the filesystem from the terminal output of `input`
+///
+/// Lines that can't be read or a filesystem that can't exist are errors, everything that is
+/// only suspicious is returned as warnings ordered by line.
+pub fn replay(input: &str) -> Result<(FileSystem, Vec<Warning>), ParseError> {
+    let lines = parse_lines(input, scan_line)?;
+
+    let mut replay = Replay {
+        fs: FileSystem::new(),
+        cwd: FileSystem::ROOT,
+        listing: None,
+        first_listings: HashMap::new(),
+        first_seen: HashMap::new(),
+        warnings: Vec::new(),
+    };
+    for (i, line) in lines.into_iter().enumerate() {
+        replay.step(i, line)?;
+    }
+    replay.end_listing();
+
+    let never_listed: Vec<Warning> = replay
+        .fs
+        .dirs()
+        .filter(|(_, dir)| !replay.first_listings.contains_key(dir))
+        .map(|(path, dir)| Warning {
+            line: replay.first_seen.get(&dir).copied().unwrap_or(1),
+            issue: Issue::NeverListed { path },
+        })
+        .collect();
+    replay.warnings.extend(never_listed);
+    replay.warnings.sort_by_key(|w| w.line);
+
+    Ok((replay.fs, replay.warnings))
+}
@@ -0,0 +1,279 @@
+use std::fmt::Display;
+
+use canvas::{Colour, Display2D, Image};
+use common::{ParseError, Solution};
+use geometry::{Grid, Vec2};
+
+pub const EXAMPLE: &str = include_str!("./example.txt");
+
+type V = Vec2<i32>;
+
+/// What a heatmap of the forest shows
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Layer {
+    Heights,
+    /// The trees that can be seen from outside of the forest
+    Visible,
+    /// The scenic scores, on a log scale
+    Scenic,
+}
+
+const HIDDEN: Colour = Colour::Rgb(70, 70, 70);
+const SIGHTLINE: Colour = Colour::White;
+const BEST: Colour = Colour::Magenta;
+
+/// The heights of the trees, from 0 to 9
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Forest {
+    heights: Grid<u8>,
+}
+
+impl Forest {
+    pub fn new(heights: Grid<u8>) -> Self {
+        Self { heights }
+    }
+
+    /// A forest of random trees, the same for the same `seed`
+    pub fn generate(width: usize, height: usize, seed: u64) -> Self {
+        // xorshift, good enough for trees
+        let mut state = seed.wrapping_mul(0x9e3779b97f4a7c15) | 1;
+        let mut heights = Grid::new(width, height, 0);
+        for (_, h) in heights.iter_mut() {
+            state ^= state << 13;
+            state ^= state >> 7;
+            state ^= state << 17;
+            *h = (state % 10) as u8;
+        }
+        Self { heights }
+    }
+
+    pub fn heights(&self) -> &Grid<u8> {
+        &self.heights
+    }
+
+    /// Every row and column of the grid once from each end, looking into the forest
+    fn lines(&self) -> impl Iterator<Item = Vec<V>> {
+        let (width, height) = (self.heights.width() as i32, self.heights.height() as i32);
+        let rows = (0..height).map(move |y| (0..width).map(|x| V::new(x, y)).collect::<Vec<_>>());
+        let columns =
+            (0..width).map(move |x| (0..height).map(|y| V::new(x, y)).collect::<Vec<_>>());
+        rows.chain(columns).flat_map(|line| {
+            let reversed = line.iter().rev().copied().collect();
+            [line, reversed]
+        })
+    }
+
+    /// Which trees can be seen from outside of the forest
+    ///
+    /// A tree is visible from an edge if it is higher than every tree before it, so one sweep
+    /// per line and direction with the highest tree so far is enough.
+    pub fn visible(&self) -> Grid<bool> {
+        let mut visible = self.heights.map(|_| false);
+        for line in self.lines() {
+            let mut highest = None;
+            for pos in line {
+                let h = Some(self.heights[pos]);
+                if h > highest {
+                    visible[pos] = true;
+                    highest = h;
+                }
+            }
+        }
+        visible
+    }
+
+    /// The scenic score of every tree
+    ///
+    /// Going along a line, a stack keeps the trees not yet blocked by a higher one. The first
+    /// tree left on it after popping the lower ones is the one that blocks the view back, so
+    /// every tree is pushed and popped once per direction.
+    pub fn scenic_scores(&self) -> Grid<u64> {
+        let mut scores = self.heights.map(|_| 1);
+        for line in self.lines() {
+            let mut stack: Vec<usize> = Vec::new();
+            for (i, pos) in line.iter().enumerate() {
+                let h = self.heights[*pos];
+                while stack.last().is_some_and(|j| self.heights[line[*j]] < h) {
+                    stack.pop();
+                }
+                let distance = match stack.last() {
+                    Some(j) => i - j,
+                    None => i,
+                };
+                scores[*pos] *= distance as u64;
+                stack.push(i);
+            }
+        }
+        scores
+    }
+
+    /// The tree with the highest scenic score and its score, the first one row by row if
+    /// there are more
+    pub fn best_tree(&self) -> Option<(V, u64)> {
+        let scores = self.scenic_scores();
+        let (pos, score) = scores
+            .iter()
+            .max_by_key(|(pos, score)| (**score, -pos.y, -pos.x))?;
+        Some((pos, *score))
+    }
+
+    /// The trees that can be seen from `pos` in the four directions, up to and including
+    /// the ones that block the view
+    pub fn sightlines(&self, pos: V) -> Vec<V> {
+        let mut seen = Vec::new();
+        for next in pos.neighbours() {
+            let step = next - pos;
+            let mut at = next;
+            while let Some(h) = self.heights.get(at) {
+                seen.push(at);
+                if *h >= self.heights[pos] {
+                    break;
+                }
+                at += step;
+            }
+        }
+        seen
+    }
+
+    /// The colour of every tree in `layer`, with the sightlines of the best tree on top
+    fn colours(&self, layer: Layer) -> Grid<Colour> {
+        let mut colours = match layer {
+            Layer::Heights => self.heights.map(|h| Colour::heat(*h as f64 / 9.0)),
+            Layer::Visible => self
+                .visible()
+                .map(|v| if *v { Colour::Green } else { HIDDEN }),
+            Layer::Scenic => {
+                let scores = self.scenic_scores();
+                let max = scores.iter().map(|(_, s)| *s).max().unwrap_or(0);
+                let scale = (max as f64).ln_1p().max(1.0);
+                scores.map(|s| Colour::heat((*s as f64).ln_1p() / scale))
+            }
+        };
+        if let Some((best, _)) = self.best_tree() {
+            for pos in self.sightlines(best) {
+                colours[pos] = SIGHTLINE;
+            }
+            colours[best] = BEST;
+        }
+        colours
+    }
+
+    /// The forest for the terminal, every tree is its height in the colour of `layer`
+    pub fn heatmap(&self, layer: Layer) -> Display2D {
+        let mut display = Display2D::new(self.heights.width(), self.heights.height());
+        for (pos, colour) in self.colours(layer).iter() {
+            let c = char::from(b'0' + self.heights[pos]);
+            display.draw_coloured(pos, c, *colour);
+        }
+        display
+    }
+
+    /// Like [`Forest::heatmap`] with one pixel per tree
+    pub fn image(&self, layer: Layer) -> Image {
+        let mut image = Image::new(self.heights.width(), self.heights.height(), [0; 3]);
+        for (pos, colour) in self.colours(layer).iter() {
+            image.set(pos, colour.rgb());
+        }
+        image
+    }
+}
+
+impl Display for Forest {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        for row in self.heights.rows() {
+            let line: String = row.iter().map(|h| char::from(b'0' + h)).collect();
+            writeln!(f, "{line}")?;
+        }
+        Ok(())
+    }
+}
+
+pub struct Day8;
+
+impl Solution for Day8 {
+    type Input = Forest;
+    type Part1 = usize;
+    type Part2 = u64;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        let heights = Grid::parse_with(input, |c| match c.to_digit(10) {
+            Some(h) => Ok(h as u8),
+            None => Err(ParseError::new("a digit", format_args!("`{c}`"))),
+        })?;
+        Ok(Forest::new(heights))
+    }
+
+    fn part1(forest: &Self::Input) -> Self::Part1 {
+        forest.visible().iter().filter(|(_, v)| **v).count()
+    }
+
+    fn part2(forest: &Self::Input) -> Self::Part2 {
+        forest
+            .scenic_scores()
+            .iter()
+            .map(|(_, s)| *s)
+            .max()
+            .unwrap_or(0)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn part1_example() {
+        let input = Day8::parse(EXAMPLE).unwrap();
+        assert_eq!(Day8::part1(&input), 21);
+    }
+
+    #[test]
+    fn part2_example() {
+        let input = Day8::parse(EXAMPLE).unwrap();
+        assert_eq!(Day8::part2(&input), 8);
+    }
+
+    #[test]
+    fn best_tree() {
+        let forest = Day8::parse(EXAMPLE).unwrap();
+        let (best, score) = forest.best_tree().unwrap();
+        assert_eq!((best, score), (V::new(2, 3), 8));
+
+        let mut seen = forest.sightlines(best);
+        seen.sort();
+        let expected = [(0, 3), (1, 3), (2, 1), (2, 2), (2, 4), (3, 3), (4, 3)];
+        assert_eq!(seen, expected.map(|(x, y)| V::new(x, y)));
+
+        let image = forest.image(Layer::Visible);
+        assert_eq!(image.get(best), Some(BEST.rgb()));
+        assert_eq!(image.get(V::new(0, 0)), Some(Colour::Green.rgb()));
+        assert_eq!(image.get(V::new(3, 1)), Some(HIDDEN.rgb()));
+        assert!(forest
+            .heatmap(Layer::Scenic)
+            .to_string()
+            .contains("\x1b[35m5"));
+    }
+
+    /// Looks from every tree in every direction, like the puzzle describes it
+    fn scenic_score_slowly(forest: &Forest, pos: V) -> u64 {
+        let seen = forest.sightlines(pos);
+        pos.neighbours()
+            .map(|next| {
+                let step = next - pos;
+                seen.iter().filter(|s| (**s - pos).signum() == step).count() as u64
+            })
+            .product()
+    }
+
+    #[test]
+    fn generated_forest() {
+        let forest = Forest::generate(37, 23, 8);
+        let input = Day8::parse(&forest.to_string()).unwrap();
+        assert_eq!(input, forest);
+
+        let scores = forest.scenic_scores();
+        for (pos, score) in scores.iter() {
+            assert_eq!(*score, scenic_score_slowly(&forest, pos), "{pos:?}");
+        }
+    }
+}
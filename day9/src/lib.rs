@@ -0,0 +1,322 @@
+use std::{
+    collections::HashSet,
+    error::Error,
+    io::{self, Write},
+};
+
+use canvas::{Animation, Colour, Display2D, Image};
+use common::{parse_lines, ParseError, Solution};
+use geometry::Vec2;
+
+pub const EXAMPLE: &str = include_str!("./example.txt");
+
+pub type V = Vec2<i32>;
+
+/// A rope of knots, every knot follows the one in front of it
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Rope {
+    knots: Vec<V>,
+}
+
+impl Rope {
+    /// A rope with all `knots` on the start, panics for no knots
+    pub fn new(knots: usize) -> Self {
+        assert!(knots > 0, "a rope needs at least one knot");
+        Self {
+            knots: vec![V::new(0, 0); knots],
+        }
+    }
+
+    /// Every knot from the head to the tail
+    pub fn knots(&self) -> &[V] {
+        &self.knots
+    }
+
+    pub fn head(&self) -> V {
+        self.knots[0]
+    }
+
+    pub fn tail(&self) -> V {
+        self.knots[self.knots.len() - 1]
+    }
+
+    /// Moves the head one step in `direction`, every knot that isn't touching the one in
+    /// front of it anymore moves one step towards it
+    pub fn step(&mut self, direction: V) {
+        self.knots[0] += direction;
+        for i in 1..self.knots.len() {
+            let front = self.knots[i - 1];
+            let knot = &mut self.knots[i];
+            if front.chebyshev(knot) < 2 {
+                // the knots behind don't move either
+                break;
+            }
+            *knot += (front - *knot).signum();
+        }
+    }
+}
+
+/// Pulls `rope` through all moves, `each_move` sees the rope and the cells the knot
+/// `tracked` has been on after every move
+fn simulate(
+    moves: &[(V, u32)],
+    rope: &mut Rope,
+    tracked: usize,
+    mut each_move: impl FnMut(&Rope, &HashSet<V>),
+) -> usize {
+    let mut been = HashSet::from([rope.knots()[tracked]]);
+
+    for (mov, amount) in moves {
+        for _ in 0..*amount {
+            rope.step(*mov);
+            been.insert(rope.knots()[tracked]);
+        }
+
+        each_move(rope, &been);
+    }
+
+    been.len()
+}
+
+/// How many cells the knot `knot` of a rope of `knots` visits, the start included
+pub fn visited(moves: &[(V, u32)], knots: usize, knot: usize) -> usize {
+    assert!(knot < knots, "the rope has no knot {knot}");
+    simulate(moves, &mut Rope::new(knots), knot, |_, _| {})
+}
+
+pub struct Day9;
+
+impl Solution for Day9 {
+    type Input = Vec<(V, u32)>;
+    type Part1 = usize;
+    type Part2 = usize;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        parse_lines(input, |s| {
+            let m = s.one_of(&[
+                ("R", V::new(1, 0)),
+                ("D", V::new(0, 1)),
+                ("L", V::new(-1, 0)),
+                ("U", V::new(0, -1)),
+            ])?;
+            s.tag(" ")?;
+            Ok((m, s.number()?))
+        })
+    }
+
+    fn part1(input: &Self::Input) -> Self::Part1 {
+        visited(input, 2, 1)
+    }
+
+    fn part2(input: &Self::Input) -> Self::Part2 {
+        visited(input, 10, 9)
+    }
+}
+
+/// The corners of the box the head stays in, the other knots follow it so they do too
+pub fn bounds(moves: &[(V, u32)]) -> (V, V) {
+    let mut head = V::new(0, 0);
+    let (mut min, mut max) = (head, head);
+    for (mov, amount) in moves {
+        head += *mov * *amount as i32;
+        min = V::new(min.x.min(head.x), min.y.min(head.y));
+        max = V::new(max.x.max(head.x), max.y.max(head.y));
+    }
+    (min, max)
+}
+
+/// How the playback in the terminal fits the rope on the screen
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Framing {
+    /// The whole run at once, every character covers `scale` by `scale` cells
+    Whole { scale: u32 },
+    /// A window that scrolls along when the head gets close to its edge
+    Follow { width: usize, height: usize },
+}
+
+impl Framing {
+    /// Shows the whole run in at most `width` by `height` characters
+    pub fn fit(moves: &[(V, u32)], width: usize, height: usize) -> Self {
+        let (min, max) = bounds(moves);
+        let size = max - min + V::new(1, 1);
+        let scale = |cells: i32, chars: usize| (cells as u32).div_ceil(chars.max(1) as u32);
+        Framing::Whole {
+            scale: scale(size.x, width).max(scale(size.y, height)).max(1),
+        }
+    }
+}
+
+/// Draws the trail and then the knots from the tail up, so the knots in front are on top
+fn draw(rope: &Rope, been: &HashSet<V>, mut put: impl FnMut(V, char, Option<Colour>)) {
+    for v in been {
+        put(*v, '#', None);
+    }
+    for (i, knot) in rope.knots().iter().enumerate().rev() {
+        let (c, colour) = match i {
+            0 => ('H', Colour::Red),
+            1 if rope.knots().len() == 2 => ('T', Colour::Yellow),
+            i => (char::from_digit(i as u32 % 10, 10).unwrap(), Colour::Yellow),
+        };
+        put(*knot, c, Some(colour));
+    }
+}
+
+/// Plays the rope of `knots` knots move by move in the terminal, with the trail of the tail
+pub fn play<W: Write>(
+    moves: &[(V, u32)],
+    knots: usize,
+    framing: Framing,
+    animation: &mut Animation<W>,
+) -> io::Result<()> {
+    let (min, max) = bounds(moves);
+    let mut d = match framing {
+        Framing::Whole { scale } => {
+            let size = (max - min) / scale as i32 + V::new(1, 1);
+            Display2D::with_background(size.x as usize, size.y as usize, '.')
+        }
+        Framing::Follow { width, height } => Display2D::with_background(width, height, '.'),
+    };
+    let window = V::new(d.width() as i32, d.height() as i32);
+    let margin = window / 4;
+    let mut origin = -window / 2;
+
+    let mut result = Ok(());
+    simulate(moves, &mut Rope::new(knots), knots - 1, |rope, been| {
+        if result.is_err() {
+            return;
+        }
+        let to_screen: Box<dyn Fn(V) -> V> = match framing {
+            Framing::Whole { scale } => Box::new(move |v| (v - min) / scale as i32),
+            Framing::Follow { .. } => {
+                // scroll just enough to keep the head away from the edges
+                let head = rope.head() - origin;
+                let low = head - margin;
+                let high = head + margin - window + V::new(1, 1);
+                origin += V::new(low.x.min(0) + high.x.max(0), low.y.min(0) + high.y.max(0));
+                Box::new(move |v| v - origin)
+            }
+        };
+
+        d.clear();
+        draw(rope, been, |v, c, colour| match colour {
+            Some(colour) => d.draw_coloured(to_screen(v), c, colour),
+            None => d.draw(to_screen(v), c),
+        });
+        result = animation.frame(&d);
+    });
+    result
+}
+
+/// The colours of the pictures, the background first
+pub const PALETTE: [[u8; 3]; 4] = [[0, 0, 0], [90, 90, 90], [229, 229, 16], [205, 49, 49]];
+
+/// Draws the rope of `knots` knots after every move as a picture of the whole run, every cell
+/// is `pixel` by `pixel` pixels in the colours of [`PALETTE`]
+pub fn frames(
+    moves: &[(V, u32)],
+    knots: usize,
+    pixel: usize,
+    mut each_frame: impl FnMut(&Image) -> io::Result<()>,
+) -> io::Result<()> {
+    let (min, max) = bounds(moves);
+    let size = max - min + V::new(1, 1);
+
+    let mut result = Ok(());
+    simulate(moves, &mut Rope::new(knots), knots - 1, |rope, been| {
+        if result.is_err() {
+            return;
+        }
+        let mut image = Image::new(size.x as usize, size.y as usize, PALETTE[0]);
+        draw(rope, been, |v, c, _| {
+            let colour = match c {
+                'H' => PALETTE[3],
+                '#' => PALETTE[1],
+                _ => PALETTE[2],
+            };
+            image.set(v - min, colour);
+        });
+        result = each_frame(&image.scaled(pixel));
+    });
+    result
+}
+
+/// Replays the rope of `part` move by move, scaled down to fit a terminal of 80 by 40
+pub fn animate<W: Write>(
+    input: &str,
+    part: u8,
+    animation: &mut Animation<W>,
+) -> Result<(), Box<dyn Error>> {
+    let moves = Day9::parse(input)?;
+    let knots = if part == 1 { 2 } else { 10 };
+    play(&moves, knots, Framing::fit(&moves, 80, 40), animation)?;
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use std::time::Duration;
+
+    use super::*;
+
+    #[test]
+    fn part1_example() {
+        let input = Day9::parse(EXAMPLE).unwrap();
+        assert_eq!(Day9::part1(&input), 88);
+    }
+
+    #[test]
+    fn part2_example() {
+        let input = Day9::parse(EXAMPLE).unwrap();
+        assert_eq!(Day9::part2(&input), 36);
+    }
+
+    #[test]
+    fn any_knot() {
+        let small = Day9::parse("R 4\nU 4\nL 3\nD 1\nR 4\nD 1\nL 5\nR 2\n").unwrap();
+        assert_eq!(visited(&small, 2, 1), 13);
+        assert_eq!(visited(&small, 10, 9), 1);
+        // the knots of a long rope move just like those of a short one
+        assert_eq!(visited(&small, 10, 1), 13);
+        assert_eq!(visited(&small, 1, 0), visited(&small, 10, 0));
+
+        let mut rope = Rope::new(3);
+        for _ in 0..3 {
+            rope.step(V::new(1, 0));
+        }
+        rope.step(V::new(0, -1));
+        rope.step(V::new(0, -1));
+        assert_eq!(rope.knots(), [V::new(3, -2), V::new(3, -1), V::new(2, -1)]);
+        assert_eq!((rope.head(), rope.tail()), (V::new(3, -2), V::new(2, -1)));
+    }
+
+    #[test]
+    fn playback() {
+        let moves = Day9::parse(EXAMPLE).unwrap();
+        let (min, max) = bounds(&moves);
+        assert_eq!(max - min, V::new(25, 20));
+        assert_eq!(Framing::fit(&moves, 13, 40), Framing::Whole { scale: 2 });
+
+        let mut sizes = Vec::new();
+        frames(&moves, 10, 2, |image| {
+            sizes.push((image.width(), image.height()));
+            Ok(())
+        })
+        .unwrap();
+        assert_eq!(sizes, vec![(52, 42); moves.len()]);
+
+        let mut out = Vec::new();
+        let framing = Framing::Follow {
+            width: 8,
+            height: 4,
+        };
+        play(
+            &moves,
+            10,
+            framing,
+            &mut Animation::new(&mut out, Duration::ZERO),
+        )
+        .unwrap();
+        let out = String::from_utf8(out).unwrap();
+        assert_eq!(out.matches('H').count(), moves.len());
+    }
+}
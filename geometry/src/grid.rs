@@ -0,0 +1,239 @@
+use std::{
+    fmt::Display,
+    ops::{Index, IndexMut},
+    str::FromStr,
+};
+
+use common::ParseError;
+
+use crate::Vec2;
+
+/// A rectangle of cells stored row by row, indexed with signed positions.
+///
+/// Positions outside of the grid, including negative ones, are never an error: `get` just
+/// returns `None` for them, so callers can look at neighbours without checking the edges.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct Grid<T> {
+    width: usize,
+    height: usize,
+    cells: Vec<T>,
+}
+
+impl<T: Clone> Grid<T> {
+    pub fn new(width: usize, height: usize, fill: T) -> Self {
+        Self {
+            width,
+            height,
+            cells: vec![fill; width * height],
+        }
+    }
+}
+
+impl<T> Grid<T> {
+    /// Parses every character of `s` into a cell, one line per row.
+    ///
+    /// Lines shorter than the longest one are padded as if they ended in spaces, empty lines
+    /// at the end are ignored. The errors of `cell` are moved to the character they come from.
+    pub fn parse_with(
+        s: &str,
+        mut cell: impl FnMut(char) -> Result<T, ParseError>,
+    ) -> Result<Self, ParseError> {
+        let s = s.trim_end_matches(['\r', '\n']);
+        let width = s.lines().map(|l| l.chars().count()).max().unwrap_or(0);
+
+        let mut cells = Vec::new();
+        let mut height = 0;
+        for (y, l) in s.lines().enumerate() {
+            let mut len = 0;
+            for (x, c) in l.chars().enumerate() {
+                cells.push(cell(c).map_err(|e| e.offset(y, x))?);
+                len += 1;
+            }
+            for x in len..width {
+                cells.push(cell(' ').map_err(|e| e.offset(y, x))?);
+            }
+            height += 1;
+        }
+
+        Ok(Self {
+            width,
+            height,
+            cells,
+        })
+    }
+
+    pub fn width(&self) -> usize {
+        self.width
+    }
+
+    pub fn height(&self) -> usize {
+        self.height
+    }
+
+    fn index_of(&self, pos: Vec2<i32>) -> Option<usize> {
+        let x = usize::try_from(pos.x).ok().filter(|x| *x < self.width)?;
+        let y = usize::try_from(pos.y).ok().filter(|y| *y < self.height)?;
+        Some(y * self.width + x)
+    }
+
+    fn pos_of(&self, i: usize) -> Vec2<i32> {
+        Vec2::new((i % self.width) as i32, (i / self.width) as i32)
+    }
+
+    pub fn contains(&self, pos: Vec2<i32>) -> bool {
+        self.index_of(pos).is_some()
+    }
+
+    pub fn get(&self, pos: Vec2<i32>) -> Option<&T> {
+        self.index_of(pos).map(|i| &self.cells[i])
+    }
+
+    pub fn get_mut(&mut self, pos: Vec2<i32>) -> Option<&mut T> {
+        self.index_of(pos).map(|i| &mut self.cells[i])
+    }
+
+    /// Every cell with its position, row by row.
+    pub fn iter(&self) -> impl Iterator<Item = (Vec2<i32>, &T)> {
+        self.cells
+            .iter()
+            .enumerate()
+            .map(|(i, c)| (self.pos_of(i), c))
+    }
+
+    pub fn iter_mut(&mut self) -> impl Iterator<Item = (Vec2<i32>, &mut T)> {
+        let width = self.width;
+        self.cells
+            .iter_mut()
+            .enumerate()
+            .map(move |(i, c)| (Vec2::new((i % width) as i32, (i / width) as i32), c))
+    }
+
+    /// The position of the first cell, row by row, that matches `pred`.
+    pub fn position(&self, mut pred: impl FnMut(&T) -> bool) -> Option<Vec2<i32>> {
+        self.cells
+            .iter()
+            .position(&mut pred)
+            .map(|i| self.pos_of(i))
+    }
+
+    /// The four orthogonal neighbours of `pos` that are inside the grid.
+    pub fn neighbours(&self, pos: Vec2<i32>) -> impl Iterator<Item = (Vec2<i32>, &T)> {
+        pos.neighbours().filter_map(|n| self.get(n).map(|c| (n, c)))
+    }
+
+    /// The eight orthogonal and diagonal neighbours of `pos` that are inside the grid.
+    pub fn neighbours_diagonal(&self, pos: Vec2<i32>) -> impl Iterator<Item = (Vec2<i32>, &T)> {
+        pos.neighbours_diagonal()
+            .filter_map(|n| self.get(n).map(|c| (n, c)))
+    }
+
+    pub fn row(&self, y: usize) -> &[T] {
+        &self.cells[y * self.width..(y + 1) * self.width]
+    }
+
+    pub fn rows(&self) -> impl Iterator<Item = &[T]> {
+        self.cells.chunks(self.width.max(1))
+    }
+
+    pub fn column(&self, x: usize) -> impl Iterator<Item = &T> {
+        assert!(x < self.width, "column {x} is outside of the grid");
+        self.cells.iter().skip(x).step_by(self.width)
+    }
+
+    pub fn columns(&self) -> impl Iterator<Item = impl Iterator<Item = &T>> {
+        (0..self.width).map(|x| self.column(x))
+    }
+
+    /// A grid of the same size with `f` applied to every cell.
+    pub fn map<U>(&self, f: impl FnMut(&T) -> U) -> Grid<U> {
+        Grid {
+            width: self.width,
+            height: self.height,
+            cells: self.cells.iter().map(f).collect(),
+        }
+    }
+}
+
+impl<T> Index<Vec2<i32>> for Grid<T> {
+    type Output = T;
+
+    fn index(&self, pos: Vec2<i32>) -> &Self::Output {
+        self.get(pos)
+            .unwrap_or_else(|| panic!("{pos} is outside of the grid"))
+    }
+}
+
+impl<T> IndexMut<Vec2<i32>> for Grid<T> {
+    fn index_mut(&mut self, pos: Vec2<i32>) -> &mut Self::Output {
+        self.get_mut(pos)
+            .unwrap_or_else(|| panic!("{pos} is outside of the grid"))
+    }
+}
+
+impl<T: TryFrom<char, Error = ParseError>> FromStr for Grid<T> {
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Self::parse_with(s, T::try_from)
+    }
+}
+
+impl<T: Display> Display for Grid<T> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        for row in self.rows() {
+            for cell in row {
+                write!(f, "{cell}")?;
+            }
+            writeln!(f)?;
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn walls(c: char) -> Result<bool, ParseError> {
+        match c {
+            '#' => Ok(true),
+            '.' | ' ' => Ok(false),
+            _ => Err(ParseError::new("`#` or `.`", format_args!("`{c}`"))),
+        }
+    }
+
+    #[test]
+    fn parse_with() {
+        let grid = Grid::parse_with("#.#\n#\n..\n\n", walls).unwrap();
+        assert_eq!((grid.width(), grid.height()), (3, 3));
+        assert_eq!(grid.row(1), [true, false, false]);
+        assert_eq!(grid.column(2).collect::<Vec<_>>(), [&true, &false, &false]);
+
+        let error = Grid::parse_with("#.#\n#.x", walls).unwrap_err();
+        assert_eq!((error.line, error.column), (2, 3));
+        assert_eq!(error.found, "`x`");
+
+        // padding goes through `cell` too
+        let error = Grid::parse_with("#.#\n#", |c| match c {
+            ' ' => Err(ParseError::new("a full row", "a space")),
+            c => walls(c),
+        })
+        .unwrap_err();
+        assert_eq!((error.line, error.column), (2, 2));
+    }
+
+    #[test]
+    fn outside() {
+        let mut grid = Grid::new(3, 2, 0);
+        grid[Vec2::new(2, 1)] = 5;
+        assert_eq!(grid.get(Vec2::new(2, 1)), Some(&5));
+        assert_eq!(grid.get(Vec2::new(-1, 0)), None);
+        assert_eq!(grid.get(Vec2::new(0, -1)), None);
+        assert_eq!(grid.get(Vec2::new(3, 0)), None);
+        assert_eq!(grid.get(Vec2::new(0, 2)), None);
+        assert!(grid.get_mut(Vec2::new(-3, -3)).is_none());
+
+        let neighbours: Vec<_> = grid.neighbours(Vec2::new(0, 0)).map(|(p, _)| p).collect();
+        assert_eq!(neighbours, [Vec2::new(1, 0), Vec2::new(0, 1)]);
+    }
+}
@@ -0,0 +1,217 @@
+use std::ops::RangeInclusive;
+
+use num::PrimInt;
+
+/// A set of integers stored as sorted, inclusive intervals.
+///
+/// Intervals that overlap or touch are merged when they are inserted, `1..=3` and `4..=6`
+/// become `1..=6`, so every set has exactly one list of intervals.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct IntervalSet<T> {
+    ranges: Vec<RangeInclusive<T>>,
+}
+
+impl<T> Default for IntervalSet<T> {
+    fn default() -> Self {
+        Self { ranges: Vec::new() }
+    }
+}
+
+/// Whether a range that ends at `end` lies before `start` with at least one number in between.
+fn apart<T: PrimInt>(end: T, start: T) -> bool {
+    end.checked_add(&T::one())
+        .is_some_and(|after| after < start)
+}
+
+impl<T: PrimInt> IntervalSet<T> {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.ranges.is_empty()
+    }
+
+    /// The merged intervals, from low to high.
+    pub fn ranges(&self) -> impl Iterator<Item = &RangeInclusive<T>> {
+        self.ranges.iter()
+    }
+
+    pub fn contains(&self, value: T) -> bool {
+        let i = self.ranges.partition_point(|r| *r.end() < value);
+        self.ranges.get(i).is_some_and(|r| *r.start() <= value)
+    }
+
+    /// Adds all numbers of `range`, an empty range changes nothing.
+    pub fn insert(&mut self, range: RangeInclusive<T>) {
+        let (mut start, mut end) = range.into_inner();
+        if start > end {
+            return;
+        }
+
+        // the intervals that overlap or touch the new one are all next to each other
+        let first = self.ranges.partition_point(|r| apart(*r.end(), start));
+        let len = self.ranges[first..].partition_point(|r| !apart(end, *r.start()));
+        if len > 0 {
+            start = start.min(*self.ranges[first].start());
+            end = end.max(*self.ranges[first + len - 1].end());
+        }
+        self.ranges.splice(first..first + len, [start..=end]);
+    }
+
+    /// The numbers that are in either set.
+    #[must_use]
+    pub fn union(&self, other: &Self) -> Self {
+        let mut union = self.clone();
+        for r in other.ranges() {
+            union.insert(r.clone());
+        }
+        union
+    }
+
+    /// The numbers that are in both sets.
+    #[must_use]
+    pub fn intersection(&self, other: &Self) -> Self {
+        let mut ranges = Vec::new();
+        let (mut a, mut b) = (
+            self.ranges.iter().peekable(),
+            other.ranges.iter().peekable(),
+        );
+        while let (Some(ra), Some(rb)) = (a.peek(), b.peek()) {
+            let start = *ra.start().max(rb.start());
+            let end = *ra.end().min(rb.end());
+            if start <= end {
+                ranges.push(start..=end);
+            }
+            // the interval that ends first can't overlap anything else
+            if ra.end() < rb.end() {
+                a.next();
+            } else {
+                b.next();
+            }
+        }
+        Self { ranges }
+    }
+
+    /// The numbers of this set that are not in `other`.
+    #[must_use]
+    pub fn difference(&self, other: &Self) -> Self {
+        let mut ranges = Vec::new();
+        for r in &self.ranges {
+            let mut start = Some(*r.start());
+            for cut in other.ranges() {
+                let Some(s) = start else { break };
+                if cut.end() < &s || cut.start() > r.end() {
+                    continue;
+                }
+                if *cut.start() > s {
+                    ranges.push(s..=*cut.start() - T::one());
+                }
+                start = cut.end().checked_add(&T::one());
+            }
+            if let Some(s) = start.filter(|s| s <= r.end()) {
+                ranges.push(s..=*r.end());
+            }
+        }
+        Self { ranges }
+    }
+
+    /// How many numbers are in the set.
+    ///
+    /// The length doesn't fit into `T` for sets that span more than half of a signed type, so
+    /// it is a `u128`. Only a set of 128-bit numbers can have more than `u128::MAX` numbers,
+    /// then the length stops at `u128::MAX`.
+    pub fn total_length(&self) -> u128 {
+        self.ranges.iter().fold(0, |len: u128, r| {
+            // the difference always fits, even when it wraps around as an `i128`
+            let steps = match (r.start().to_i128(), r.end().to_i128()) {
+                (Some(start), Some(end)) => end.wrapping_sub(start) as u128,
+                _ => r.end().to_u128().unwrap() - r.start().to_u128().unwrap(),
+            };
+            len.saturating_add(steps).saturating_add(1)
+        })
+    }
+
+    /// The numbers missing between the lowest and the highest number of the set.
+    pub fn gaps(&self) -> impl Iterator<Item = RangeInclusive<T>> + '_ {
+        self.ranges
+            .windows(2)
+            .map(|w| (*w[0].end() + T::one())..=(*w[1].start() - T::one()))
+    }
+}
+
+impl<T: PrimInt> From<RangeInclusive<T>> for IntervalSet<T> {
+    fn from(range: RangeInclusive<T>) -> Self {
+        let mut set = Self::new();
+        set.insert(range);
+        set
+    }
+}
+
+impl<T: PrimInt> FromIterator<RangeInclusive<T>> for IntervalSet<T> {
+    fn from_iter<I: IntoIterator<Item = RangeInclusive<T>>>(iter: I) -> Self {
+        let mut set = Self::new();
+        for range in iter {
+            set.insert(range);
+        }
+        set
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn ranges(set: &IntervalSet<i32>) -> Vec<RangeInclusive<i32>> {
+        set.ranges().cloned().collect()
+    }
+
+    #[test]
+    fn insert_merges_overlapping_and_touching() {
+        let set: IntervalSet<i32> = [8..=9, 1..=3, 4..=5, 12..=14, 13..=20]
+            .into_iter()
+            .collect();
+        assert_eq!(ranges(&set), [1..=5, 8..=9, 12..=20]);
+        assert_eq!(set.total_length(), 16);
+        assert_eq!(set.gaps().collect::<Vec<_>>(), [6..=7, 10..=11]);
+        assert!(set.contains(8) && !set.contains(10));
+    }
+
+    #[test]
+    fn algebra() {
+        let a: IntervalSet<i32> = [0..=10, 20..=30].into_iter().collect();
+        let b: IntervalSet<i32> = [5..=25].into_iter().collect();
+
+        assert_eq!(ranges(&a.union(&b)), [0..=30]);
+        assert_eq!(ranges(&a.intersection(&b)), [5..=10, 20..=25]);
+        assert_eq!(ranges(&a.difference(&b)), [0..=4, 26..=30]);
+        assert_eq!(ranges(&b.difference(&a)), [11..=19]);
+        assert!(a.difference(&a).is_empty());
+    }
+
+    #[test]
+    fn edges_of_the_number_type() {
+        let all = IntervalSet::from(i32::MIN..=i32::MAX);
+        let middle = IntervalSet::from(-1..=1);
+        assert_eq!(
+            ranges(&all.difference(&middle)),
+            [i32::MIN..=-2, 2..=i32::MAX]
+        );
+        assert!(IntervalSet::from(0..=i32::MAX).difference(&all).is_empty());
+
+        assert_eq!(all.total_length(), 1 << 32);
+        assert_eq!(all.difference(&middle).total_length(), (1 << 32) - 3);
+        assert_eq!(
+            IntervalSet::from(i64::MIN..=i64::MAX).total_length(),
+            1 << 64
+        );
+        assert_eq!(
+            IntervalSet::from(0..=u128::MAX - 1).total_length(),
+            u128::MAX
+        );
+        assert_eq!(
+            IntervalSet::from(i128::MIN..=i128::MAX).total_length(),
+            u128::MAX
+        );
+    }
+}
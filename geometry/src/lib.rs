@@ -0,0 +1,124 @@
+//! Small vector, grid and interval types shared by the grid and space puzzles.
+
+use std::str::FromStr;
+
+use common::{ParseError, Scanner};
+
+mod grid;
+mod interval_set;
+mod vec2;
+mod vec3;
+
+pub use grid::Grid;
+pub use interval_set::IntervalSet;
+pub use vec2::Vec2;
+pub use vec3::Vec3;
+
+/// Implements the component-wise operators for a vector type, by value and by reference.
+macro_rules! impl_ops {
+    ($vec:ident { $($c:ident),+ }) => {
+        impl_ops!(@op $vec { $($c),+ } Add add AddAssign add_assign + +=);
+        impl_ops!(@op $vec { $($c),+ } Sub sub SubAssign sub_assign - -=);
+
+        impl<T: std::ops::Neg<Output = T>> std::ops::Neg for $vec<T> {
+            type Output = $vec<T>;
+
+            fn neg(self) -> Self::Output {
+                $vec { $($c: -self.$c),+ }
+            }
+        }
+
+        impl<T: std::ops::Mul<Output = T> + Copy> std::ops::Mul<T> for $vec<T> {
+            type Output = $vec<T>;
+
+            fn mul(self, rhs: T) -> Self::Output {
+                $vec { $($c: self.$c * rhs),+ }
+            }
+        }
+
+        impl<T: std::ops::MulAssign + Copy> std::ops::MulAssign<T> for $vec<T> {
+            fn mul_assign(&mut self, rhs: T) {
+                $(self.$c *= rhs;)+
+            }
+        }
+
+        /// Every component on its own, integers round towards zero.
+        impl<T: std::ops::Div<Output = T> + Copy> std::ops::Div<T> for $vec<T> {
+            type Output = $vec<T>;
+
+            fn div(self, rhs: T) -> Self::Output {
+                $vec { $($c: self.$c / rhs),+ }
+            }
+        }
+    };
+    (@op $vec:ident { $($c:ident),+ } $op:ident $fn:ident $op_assign:ident $fn_assign:ident $sym:tt $sym_assign:tt) => {
+        impl<T: std::ops::$op<Output = T>> std::ops::$op for $vec<T> {
+            type Output = $vec<T>;
+
+            fn $fn(self, rhs: Self) -> Self::Output {
+                $vec { $($c: self.$c $sym rhs.$c),+ }
+            }
+        }
+
+        impl<T: std::ops::$op<Output = T> + Copy> std::ops::$op<&$vec<T>> for $vec<T> {
+            type Output = $vec<T>;
+
+            fn $fn(self, rhs: &$vec<T>) -> Self::Output {
+                $vec { $($c: self.$c $sym rhs.$c),+ }
+            }
+        }
+
+        impl<T: std::ops::$op<Output = T> + Copy> std::ops::$op<$vec<T>> for &$vec<T> {
+            type Output = $vec<T>;
+
+            fn $fn(self, rhs: $vec<T>) -> Self::Output {
+                $vec { $($c: self.$c $sym rhs.$c),+ }
+            }
+        }
+
+        impl<T: std::ops::$op<Output = T> + Copy> std::ops::$op<&$vec<T>> for &$vec<T> {
+            type Output = $vec<T>;
+
+            fn $fn(self, rhs: &$vec<T>) -> Self::Output {
+                $vec { $($c: self.$c $sym rhs.$c),+ }
+            }
+        }
+
+        impl<T: std::ops::$op_assign> std::ops::$op_assign for $vec<T> {
+            fn $fn_assign(&mut self, rhs: Self) {
+                $(self.$c $sym_assign rhs.$c;)+
+            }
+        }
+
+        impl<T: std::ops::$op_assign + Copy> std::ops::$op_assign<&$vec<T>> for $vec<T> {
+            fn $fn_assign(&mut self, rhs: &$vec<T>) {
+                $(self.$c $sym_assign rhs.$c;)+
+            }
+        }
+    };
+}
+
+pub(crate) use impl_ops;
+
+/// Scans `N` comma separated components.
+///
+/// Every component may be preceded by spaces and by its name, so both `498,4` and
+/// `x=2, y=18` are accepted.
+fn scan_components<T: FromStr, const N: usize>(
+    s: &mut Scanner<'_>,
+    names: [&str; N],
+) -> Result<[T; N], ParseError> {
+    let mut components = Vec::with_capacity(N);
+    for (i, name) in names.into_iter().enumerate() {
+        if i != 0 {
+            s.tag(",")?;
+        }
+        s.skip_spaces();
+        if s.try_tag(name) {
+            s.tag("=")?;
+        }
+        components.push(s.number()?);
+    }
+
+    Ok(components.try_into().ok().unwrap())
+}
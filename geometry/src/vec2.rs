@@ -0,0 +1,171 @@
+use std::{fmt::Display, str::FromStr};
+
+use num::{Signed, ToPrimitive};
+
+use common::{ParseError, Scanner};
+
+use crate::{impl_ops, scan_components};
+
+/// A 2D vector, used both for positions and for steps between them.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord, Default)]
+pub struct Vec2<T> {
+    pub x: T,
+    pub y: T,
+}
+
+impl<T> Vec2<T> {
+    pub const fn new(x: T, y: T) -> Self {
+        Self { x, y }
+    }
+}
+
+impl_ops!(Vec2 { x, y });
+
+impl<T: Signed + Copy> Vec2<T> {
+    /// The four steps to the orthogonal neighbours: right, down, left and up.
+    pub fn orthogonal() -> [Self; 4] {
+        let (o, z) = (T::one(), T::zero());
+        [
+            Self::new(o, z),
+            Self::new(z, o),
+            Self::new(-o, z),
+            Self::new(z, -o),
+        ]
+    }
+
+    /// The eight steps to the orthogonal and diagonal neighbours, row by row.
+    pub fn around() -> [Self; 8] {
+        let (o, z) = (T::one(), T::zero());
+        [
+            Self::new(-o, -o),
+            Self::new(z, -o),
+            Self::new(o, -o),
+            Self::new(-o, z),
+            Self::new(o, z),
+            Self::new(-o, o),
+            Self::new(z, o),
+            Self::new(o, o),
+        ]
+    }
+
+    /// The four orthogonal neighbours of this position.
+    pub fn neighbours(self) -> impl Iterator<Item = Self> {
+        Self::orthogonal().into_iter().map(move |d| self + d)
+    }
+
+    /// The eight orthogonal and diagonal neighbours of this position.
+    pub fn neighbours_diagonal(self) -> impl Iterator<Item = Self> {
+        Self::around().into_iter().map(move |d| self + d)
+    }
+
+    /// Turns a quarter to the left, from `x` towards `-y`.
+    ///
+    /// With `y` pointing down, like on the screen, this is counter clockwise.
+    pub fn rotate_left(self) -> Self {
+        Self::new(self.y, -self.x)
+    }
+
+    /// Turns a quarter to the right, from `x` towards `y`.
+    ///
+    /// With `y` pointing down, like on the screen, this is clockwise.
+    pub fn rotate_right(self) -> Self {
+        Self::new(-self.y, self.x)
+    }
+
+    /// The sign of every component, a step of at most one in each direction.
+    pub fn signum(self) -> Self {
+        Self::new(self.x.signum(), self.y.signum())
+    }
+
+    pub fn manhattan(&self, other: &Self) -> T {
+        (self.x - other.x).abs() + (self.y - other.y).abs()
+    }
+}
+
+impl<T: Signed + Copy + Ord> Vec2<T> {
+    pub fn chebyshev(&self, other: &Self) -> T {
+        (self.x - other.x).abs().max((self.y - other.y).abs())
+    }
+}
+
+impl<T: ToPrimitive> Vec2<T> {
+    pub fn euclidean(&self, other: &Self) -> f64 {
+        let dx = self.x.to_f64().unwrap() - other.x.to_f64().unwrap();
+        let dy = self.y.to_f64().unwrap() - other.y.to_f64().unwrap();
+        dx.hypot(dy)
+    }
+}
+
+impl<T: Display> Display for Vec2<T> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{},{}", self.x, self.y)
+    }
+}
+
+impl<T: FromStr> Vec2<T> {
+    /// Scans a vector written as `1,2` or as `x=1, y=2`.
+    pub fn scan(s: &mut Scanner<'_>) -> Result<Self, ParseError> {
+        let [x, y] = scan_components(s, ["x", "y"])?;
+        Ok(Self::new(x, y))
+    }
+}
+
+impl<T: FromStr> FromStr for Vec2<T> {
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Scanner::parse(s, Self::scan)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    type V = Vec2<i32>;
+
+    #[test]
+    fn rotations() {
+        let right = V::new(1, 0);
+        assert_eq!(right.rotate_left(), V::new(0, -1));
+        assert_eq!(right.rotate_right(), V::new(0, 1));
+        assert_eq!(V::new(2, 3).rotate_left().rotate_right(), V::new(2, 3));
+
+        let mut v = V::new(2, 3);
+        for _ in 0..4 {
+            v = v.rotate_right();
+        }
+        assert_eq!(v, V::new(2, 3));
+    }
+
+    #[test]
+    fn neighbours() {
+        let pos = V::new(5, 7);
+        let around: Vec<V> = pos.neighbours_diagonal().collect();
+        let expected = [
+            (4, 6),
+            (5, 6),
+            (6, 6),
+            (4, 7),
+            (6, 7),
+            (4, 8),
+            (5, 8),
+            (6, 8),
+        ];
+        assert_eq!(around, expected.map(|(x, y)| V::new(x, y)));
+
+        let orthogonal: Vec<V> = pos.neighbours().collect();
+        let expected = [(6, 7), (5, 8), (4, 7), (5, 6)];
+        assert_eq!(orthogonal, expected.map(|(x, y)| V::new(x, y)));
+    }
+
+    #[test]
+    fn scan() {
+        assert_eq!("1,2".parse(), Ok(V::new(1, 2)));
+        assert_eq!("x=1, y=2".parse(), Ok(V::new(1, 2)));
+        assert_eq!("x=-1, y=-20".parse(), Ok(V::new(-1, -20)));
+
+        let error = "1;2".parse::<V>().unwrap_err();
+        assert_eq!((error.column, error.expected.as_str()), (2, "`,`"));
+    }
+}
@@ -0,0 +1,159 @@
+use std::{fmt::Display, str::FromStr};
+
+use num::{Signed, ToPrimitive};
+
+use common::{ParseError, Scanner};
+
+use crate::{impl_ops, scan_components};
+
+/// A 3D vector, used both for positions and for steps between them.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord, Default)]
+pub struct Vec3<T> {
+    pub x: T,
+    pub y: T,
+    pub z: T,
+}
+
+impl<T> Vec3<T> {
+    pub const fn new(x: T, y: T, z: T) -> Self {
+        Self { x, y, z }
+    }
+}
+
+impl_ops!(Vec3 { x, y, z });
+
+impl<T: Signed + Copy> Vec3<T> {
+    /// The six steps to the neighbours sharing a face.
+    pub fn orthogonal() -> [Self; 6] {
+        let (o, z) = (T::one(), T::zero());
+        [
+            Self::new(-o, z, z),
+            Self::new(o, z, z),
+            Self::new(z, -o, z),
+            Self::new(z, o, z),
+            Self::new(z, z, -o),
+            Self::new(z, z, o),
+        ]
+    }
+
+    /// The six neighbours sharing a face with this position.
+    pub fn neighbours(self) -> impl Iterator<Item = Self> {
+        Self::orthogonal().into_iter().map(move |d| self + d)
+    }
+
+    /// The 26 neighbours sharing a face, an edge or a corner with this position.
+    pub fn neighbours_diagonal(self) -> impl Iterator<Item = Self> {
+        let steps = [-T::one(), T::zero(), T::one()];
+        steps
+            .into_iter()
+            .flat_map(move |x| {
+                steps
+                    .into_iter()
+                    .flat_map(move |y| steps.into_iter().map(move |z| Self::new(x, y, z)))
+            })
+            .filter(|d| *d != Self::new(T::zero(), T::zero(), T::zero()))
+            .map(move |d| self + d)
+    }
+
+    /// Turns a quarter around the `x` axis, from `y` towards `z`.
+    pub fn rotate_x(self) -> Self {
+        Self::new(self.x, -self.z, self.y)
+    }
+
+    /// Turns a quarter around the `y` axis, from `z` towards `x`.
+    pub fn rotate_y(self) -> Self {
+        Self::new(self.z, self.y, -self.x)
+    }
+
+    /// Turns a quarter around the `z` axis, from `x` towards `y`.
+    pub fn rotate_z(self) -> Self {
+        Self::new(-self.y, self.x, self.z)
+    }
+
+    pub fn manhattan(&self, other: &Self) -> T {
+        (self.x - other.x).abs() + (self.y - other.y).abs() + (self.z - other.z).abs()
+    }
+}
+
+impl<T: Signed + Copy + Ord> Vec3<T> {
+    pub fn chebyshev(&self, other: &Self) -> T {
+        (self.x - other.x)
+            .abs()
+            .max((self.y - other.y).abs())
+            .max((self.z - other.z).abs())
+    }
+}
+
+impl<T: ToPrimitive> Vec3<T> {
+    pub fn euclidean(&self, other: &Self) -> f64 {
+        let dx = self.x.to_f64().unwrap() - other.x.to_f64().unwrap();
+        let dy = self.y.to_f64().unwrap() - other.y.to_f64().unwrap();
+        let dz = self.z.to_f64().unwrap() - other.z.to_f64().unwrap();
+        (dx * dx + dy * dy + dz * dz).sqrt()
+    }
+}
+
+impl<T: Display> Display for Vec3<T> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{},{},{}", self.x, self.y, self.z)
+    }
+}
+
+impl<T: FromStr> Vec3<T> {
+    /// Scans a vector written as `1,2,3` or as `x=1, y=2, z=3`.
+    pub fn scan(s: &mut Scanner<'_>) -> Result<Self, ParseError> {
+        let [x, y, z] = scan_components(s, ["x", "y", "z"])?;
+        Ok(Self::new(x, y, z))
+    }
+}
+
+impl<T: FromStr> FromStr for Vec3<T> {
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Scanner::parse(s, Self::scan)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    type V = Vec3<i32>;
+
+    #[test]
+    fn rotations() {
+        let (x, y, z) = (V::new(1, 0, 0), V::new(0, 1, 0), V::new(0, 0, 1));
+        assert_eq!(y.rotate_x(), z);
+        assert_eq!(z.rotate_y(), x);
+        assert_eq!(x.rotate_z(), y);
+        assert_eq!(x.rotate_x(), x);
+
+        assert_eq!(z.rotate_x(), -y);
+
+        let v = V::new(1, 2, 3);
+        let four_times = |rotate: fn(V) -> V| rotate(rotate(rotate(rotate(v))));
+        assert_eq!(four_times(V::rotate_x), v);
+        assert_eq!(four_times(V::rotate_y), v);
+        assert_eq!(four_times(V::rotate_z), v);
+    }
+
+    #[test]
+    fn neighbours() {
+        let pos = V::new(1, 1, 1);
+        let around: Vec<V> = pos.neighbours_diagonal().collect();
+        assert_eq!(around.len(), 26);
+        assert_eq!(around[0], V::new(0, 0, 0));
+        assert_eq!(around[1], V::new(0, 0, 1));
+        assert_eq!(around[25], V::new(2, 2, 2));
+        assert!(!around.contains(&pos));
+        assert!(pos.neighbours().all(|n| around.contains(&n)));
+    }
+
+    #[test]
+    fn scan() {
+        assert_eq!("1,2,3".parse(), Ok(V::new(1, 2, 3)));
+        assert_eq!("x=1, y=2, z=-3".parse(), Ok(V::new(1, 2, -3)));
+        assert_eq!("1,2".parse::<V>().unwrap_err().column, 4);
+    }
+}